@@ -13,14 +13,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::displayer::{DisplayProxy, DisplayerOf};
+use crate::displayer::{DisplayProxy, DisplayWithProxy, DisplayerOf, DisplayerWith, UnitLabel};
+use crate::instant::Instant;
+use crate::ops::{
+    AbsDiffOps, AbsOps, CheckedOps, DivOps, GcdOps, MidpointOps, SaturatingOps, ScaleOps,
+    SignedOps, UsizeOps, WrappingOps,
+};
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
 use crate::trait_flag::{self, TraitFlags};
+use crate::type_name::short_type_name;
 use core::cmp::Ordering;
+use core::convert::TryFrom;
 use core::fmt;
 use core::hash::{Hash, Hasher};
+use core::iter::Sum;
 use core::marker::PhantomData;
-use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+use core::num::TryFromIntError;
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Not, Range, RangeBounds, Rem, Shl, ShlAssign, Shr, ShrAssign, Sub,
+    SubAssign,
+};
+use core::str::FromStr;
+#[cfg(feature = "num-traits")]
+use num_traits::Zero;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -86,6 +102,24 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// assert_eq!(y - y, Amount::<Oranges, u64>::from(0));
 /// ```
 ///
+/// Amounts can be summed up, both by value and by reference:
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::Amount;
+///
+/// enum Grams {}
+/// type Weight = Amount<Grams, u64>;
+///
+/// let weights = vec![Weight::from(1), Weight::from(20), Weight::from(300)];
+/// assert_eq!(weights.iter().sum::<Weight>(), Weight::from(321));
+/// assert_eq!(weights.into_iter().sum::<Weight>(), Weight::from(321));
+/// ```
+///
 /// Multiplication of amounts is not supported: multiplying meters by
 /// meters gives square meters. However, you can scale an amount by a
 /// scalar or divide amounts:
@@ -106,6 +140,67 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// assert_eq!(3, (x * 3) / x);
 /// ```
 ///
+/// Dividing an amount by a scalar scales it down and keeps the unit.
+/// Don't confuse it with dividing an amount by an amount, which
+/// gives a scalar:
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::Amount;
+///
+/// enum Meters {}
+///
+/// let x = Amount::<Meters, u64>::from(10);
+/// assert_eq!(x / 2, Amount::<Meters, u64>::from(5));
+/// assert_eq!(x / Amount::from(2), 5u64);
+///
+/// let mut y = x;
+/// y /= 5;
+/// assert_eq!(y, Amount::from(2));
+/// ```
+///
+/// Similarly, the remainder of dividing amounts is a scalar, as the
+/// unit cancels out:
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::Amount;
+///
+/// enum Bytes {}
+///
+/// let x = Amount::<Bytes, u64>::from(10);
+/// assert_eq!(1u64, x % Amount::from(3));
+/// assert_eq!(0, x % x);
+/// ```
+///
+/// Amounts can be formatted in hexadecimal, octal and binary if the
+/// `Repr` can. Formatting flags (like width and `#`) are respected:
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::Amount;
+///
+/// enum Register {}
+///
+/// let x = Amount::<Register, u32>::from(5);
+/// assert_eq!(format!("{:#010b}", x), "0b00000101");
+/// assert_eq!(format!("{:#x}", x), "0x5");
+/// assert_eq!(format!("{:X}", Amount::<Register, u32>::from(255)), "FF");
+/// assert_eq!(format!("{:o}", Amount::<Register, u32>::from(8)), "10");
+/// ```
+///
 /// Note that the unit is only available at compile time, thus using
 /// `Amount` instead of `u64` doesn't incur any runtime penalty:
 ///
@@ -147,6 +242,42 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// }
 /// ```
 ///
+/// Amounts can be parsed from strings. Just like with `serde`, the
+/// textual forms of `Amount<Unit, Repr>` and `Repr` are identical:
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::Amount;
+/// enum Seconds {}
+/// type Timeout = Amount<Seconds, u64>;
+///
+/// assert_eq!("42".parse::<Timeout>(), Ok(Timeout::from(42)));
+/// assert_eq!("-1".parse::<Timeout>(), "-1".parse::<u64>().map(Timeout::from));
+/// assert!("42s".parse::<Timeout>().is_err());
+/// ```
+///
+/// Amounts of primitive integers have `MIN` and `MAX` constants:
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::Amount;
+/// enum Bytes {}
+/// type Size = Amount<Bytes, u8>;
+///
+/// const LIMIT: Size = Size::MAX;
+/// assert_eq!(LIMIT.get(), 255);
+/// assert_eq!(Size::MIN.get(), 0);
+/// assert!(Size::from(7) < Size::MAX);
+/// ```
+///
 /// You can also declare constants of `Amount<Unit, Repr>` using `new`
 /// function:
 /// ```
@@ -206,6 +337,27 @@ impl<const TF: TraitFlags, Unit, Repr: Copy> Amount<TF, Unit, Repr> {
     /// let three_apples = Amount::<Apples, u64>::from(3);
     /// assert_eq!(9, (three_apples * 3).get());
     /// ```
+    ///
+    /// It's not a `const fn` (that needs Rust 1.61, because of the `Copy`
+    /// bound). To derive constants from other constants, dereference
+    /// `get_ref` instead, which is a `const fn`:
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Meters {}
+    /// type Distance = Amount<Meters, u64>;
+    ///
+    /// const ASTRONOMICAL_UNIT: Distance = Distance::new(149_597_870_700);
+    /// const LIGHT_MINUTES_PER_AU: u64 = *ASTRONOMICAL_UNIT.get_ref() / 17_987_547_480;
+    /// assert_eq!(LIGHT_MINUTES_PER_AU, 8);
+    /// ```
+    #[must_use]
     pub fn get(&self) -> Repr {
         self.0
     }
@@ -219,14 +371,10 @@ impl<const TF: TraitFlags, Unit, Repr> Amount<TF, Unit, Repr> {
     pub const fn new(repr: Repr) -> Self {
         Self(repr, PhantomData)
     }
-}
 
-#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<const TF: TraitFlags, Unit: Default, Repr> Amount<TF, Unit, Repr> {
-    // @TODO similar but without &self
-    //
-    /// Provides a useful shortcut to access units of an amount if
-    /// they implement the `Default` trait:
+    /// Creates an amount of `Repr::default()` (zero for numbers). Unlike
+    /// [Default], it's available for all trait flags, including the
+    /// `*NoDefault` ones.
     ///
     /// ```
     /// #![cfg_attr(
@@ -234,252 +382,3168 @@ impl<const TF: TraitFlags, Unit: Default, Repr> Amount<TF, Unit, Repr> {
     ///     feature(generic_const_exprs)
     /// )]
     ///
-    /// use phantom_newtype::Amount;
+    /// use phantom_newtype::AmountNoDefault;
     ///
-    /// #[derive(Debug, Default)]
-    /// struct Seconds;
-    /// let duration = Amount::<Seconds, u64>::from(5);
+    /// enum Cents {}
     ///
-    /// assert_eq!("5 Seconds", format!("{} {:?}", duration, duration.unit()));
+    /// assert_eq!(AmountNoDefault::<Cents, u32>::zeroed(), AmountNoDefault::from(0));
     /// ```
-    pub fn unit(&self) -> Unit {
-        Default::default()
+    pub fn zeroed() -> Self
+    where
+        Repr: Default,
+    {
+        Self::new(Repr::default())
     }
-}
 
-#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<const TF: TraitFlags, Unit, Repr> Amount<TF, Unit, Repr>
-where
-    Unit: DisplayerOf<Self>, //@TODO cleanup: Amount<TF, Unit, Repr>>,
-{
-    /// `display` provides a mechanism to implement a custom display
-    /// for phantom types.
+    /// Returns a reference to the wrapped value. Unlike `get`, it
+    /// doesn't need `Repr` to be `Copy`.
     ///
     /// ```
     /// #![cfg_attr(
     ///     feature = "unstable_generic_const_own_type",
-    ///     feature(generic_const_exprs),
+    ///     feature(generic_const_exprs)
     /// )]
     ///
-    /// use phantom_newtype::DisplayerOf;
-    /// use core::fmt;
-    ///
-    /// struct Cents;
-    /// struct YearUnit;
-    /// // This causes ICE (with feature `unstable_generic_const_own_type`), see https://github.com/rust-lang/rust/issues/134044:
-    /// #[cfg(not(feature = "unstable_generic_const_own_type"))]
-    /// type Money = phantom_newtype::Amount<Cents, u64>;
-    /// // No ICE:
-    /// #[cfg(feature = "unstable_generic_const_own_type")]
-    /// type Money = phantom_newtype::AmountForFlags<{phantom_newtype::trait_flag::TRAIT_FLAGS_IS_COPY_IS_DEFAULT}, Cents, u64>;
+    /// use phantom_newtype::AmountNoCopy;
     ///
-    /// impl DisplayerOf<Money> for Cents {
-    ///   fn display(amount: &Money, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    ///     write!(f, "${}.{:02}", amount.get() / 100, amount.get() % 100)
-    ///   }
-    /// }
+    /// enum Samples {}
     ///
-    /// assert_eq!(format!("{}", Money::from(1005).display()), "$10.05");
+    /// let samples = AmountNoCopy::<Samples, Vec<u8>>::from(vec![1, 2, 3]);
+    /// assert_eq!(samples.get_ref().len(), 3);
     /// ```
-    pub fn display(&self) -> DisplayProxy<'_, Self, Unit> {
-        DisplayProxy::new(self)
+    pub const fn get_ref(&self) -> &Repr {
+        &self.0
     }
-}
 
-#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<const TF: TraitFlags, Unit, Repr> From<Repr> for Amount<TF, Unit, Repr> {
-    fn from(repr: Repr) -> Self {
-        Self::new(repr)
+    /// Mutably borrows the wrapped value, to modify it in place
+    /// (without reconstructing the amount).
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::AmountNoCopy;
+    ///
+    /// enum Samples {}
+    ///
+    /// let mut samples = AmountNoCopy::<Samples, Vec<u8>>::from(vec![1, 2]);
+    /// samples.get_mut().push(3);
+    /// assert_eq!(samples.get_ref().len(), 3);
+    /// ```
+    pub fn get_mut(&mut self) -> &mut Repr {
+        &mut self.0
     }
-}
-
-// Note that we only have to write the boilerplate trait
-// implementation below because default implementations of traits put
-// unnecessary restrictions on the type parameters. E.g. deriving
-// `PartialEq<Wrapper<T>>` require `T` to implement `PartialEq`, which
-// is not what we want: `T` is phantom in our case.
 
-#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<const TF: TraitFlags, Unit, Repr: Clone> Clone for Amount<TF, Unit, Repr> {
-    fn clone(&self) -> Self {
-        Amount(self.0.clone(), PhantomData)
+    /// Consumes `self` and returns the wrapped value. Unlike `get`, it
+    /// doesn't need `Repr` to be `Copy`.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::AmountNoCopy;
+    ///
+    /// enum Words {}
+    ///
+    /// let text = AmountNoCopy::<Words, String>::from("hello".to_string());
+    /// let s: String = text.into_inner();
+    /// assert_eq!(s, "hello");
+    /// ```
+    #[must_use]
+    pub fn into_inner(self) -> Repr {
+        self.0
     }
-}
-
-#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<Unit, Repr: Copy> Copy for Amount<{ trait_flag::TRAIT_FLAGS_IS_COPY_IS_DEFAULT }, Unit, Repr> {}
-#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<Unit, Repr: Copy> Copy for Amount<{ trait_flag::TRAIT_FLAGS_IS_COPY_NO_DEFAULT }, Unit, Repr> {}
 
-#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<Unit, Repr: Default> Default
-    for Amount<{ trait_flag::TRAIT_FLAGS_IS_COPY_IS_DEFAULT }, Unit, Repr>
-{
-    fn default() -> Self {
-        Self(Default::default(), PhantomData)
-    }
-}
-#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<Unit, Repr: Default> Default
-    for Amount<{ trait_flag::TRAIT_FLAGS_NO_COPY_IS_DEFAULT }, Unit, Repr>
-{
-    fn default() -> Self {
-        Self(Default::default(), PhantomData)
+    /// Reinterprets a slice of `Repr`s as a slice of amounts, without
+    /// copying.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum NodeId {}
+    ///
+    /// let reprs: &[u64] = &[1, 2, 3];
+    /// let amounts: &[Amount<NodeId, u64>] = Amount::cast_slice(reprs);
+    /// assert_eq!(amounts[2], Amount::from(3));
+    /// ```
+    pub fn cast_slice(reprs: &[Repr]) -> &[Self] {
+        let () = Self::SAME_LAYOUT_AS_REPR;
+        // SAFETY: `Self` is `#[repr(transparent)]` over `Repr` (the other
+        // field is a zero-sized `PhantomData`), so it has the same size,
+        // alignment and validity as `Repr`. The lifetime is preserved.
+        unsafe { core::slice::from_raw_parts(reprs.as_ptr().cast::<Self>(), reprs.len()) }
     }
-}
 
-#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<const TF: TraitFlags, Unit, Repr: PartialEq> PartialEq for Amount<TF, Unit, Repr> {
-    fn eq(&self, rhs: &Self) -> bool {
-        self.0.eq(&rhs.0)
+    /// Mutable version of [Self::cast_slice].
+    pub fn cast_slice_mut(reprs: &mut [Repr]) -> &mut [Self] {
+        let () = Self::SAME_LAYOUT_AS_REPR;
+        // SAFETY: See `cast_slice`. The exclusive borrow is preserved.
+        unsafe { core::slice::from_raw_parts_mut(reprs.as_mut_ptr().cast::<Self>(), reprs.len()) }
     }
-}
 
-#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<const TF: TraitFlags, Unit, Repr: Eq> Eq for Amount<TF, Unit, Repr> {}
+    /// Compile-time assertion (evaluated once used) that `Self` and
+    /// `Repr` have the same layout, as guaranteed by
+    /// `#[repr(transparent)]`. (Indexing out of bounds fails the
+    /// evaluation: `assert!` in `const` context needs Rust 1.57.)
+    const SAME_LAYOUT_AS_REPR: () = [()][(core::mem::size_of::<Self>()
+        != core::mem::size_of::<Repr>()
+        || core::mem::align_of::<Self>() != core::mem::align_of::<Repr>())
+        as usize];
 
-#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<const TF: TraitFlags, Unit, Repr: PartialOrd> PartialOrd for Amount<TF, Unit, Repr> {
-    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
-        self.0.partial_cmp(&rhs.0)
+    /// Transforms the wrapped value, keeping the same unit.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Bytes {}
+    ///
+    /// let small = Amount::<Bytes, u32>::from(5);
+    /// let big: Amount<Bytes, u64> = small.map(u64::from);
+    /// assert_eq!(big, Amount::from(5));
+    /// assert_eq!(big.map(|b| b * 1024), Amount::from(5 * 1024));
+    /// ```
+    #[must_use]
+    pub fn map<R2>(self, f: impl FnOnce(Repr) -> R2) -> Amount<TF, Unit, R2> {
+        Amount::new(f(self.0))
     }
-}
 
-#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<const TF: TraitFlags, Unit, Repr: Ord> Ord for Amount<TF, Unit, Repr> {
-    fn cmp(&self, rhs: &Self) -> Ordering {
-        self.0.cmp(&rhs.0)
+    /// Fallible version of [Self::map].
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use core::convert::TryFrom;
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Bytes {}
+    ///
+    /// let small = Amount::<Bytes, u64>::from(5).try_map(u32::try_from);
+    /// assert_eq!(small, Ok(Amount::<Bytes, u32>::from(5)));
+    ///
+    /// let too_big = Amount::<Bytes, u64>::from(u64::MAX).try_map(u32::try_from);
+    /// assert!(too_big.is_err());
+    /// ```
+    pub fn try_map<R2, E>(
+        self,
+        f: impl FnOnce(Repr) -> Result<R2, E>,
+    ) -> Result<Amount<TF, Unit, R2>, E> {
+        f(self.0).map(Amount::new)
     }
-}
 
-#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<const TF: TraitFlags, Unit, Repr: Hash> Hash for Amount<TF, Unit, Repr> {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0.hash(state)
+    /// Changes the trait flags (like whether `Self` is `Copy`), moving
+    /// the underlying representation. The layout is the same for all
+    /// flags, so this is only a compile-time re-tag. The target flags
+    /// are best inferred from the target type alias:
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::{Amount, AmountNoCopy, AmountNoDefault};
+    ///
+    /// enum Apples {}
+    ///
+    /// let x = AmountNoCopy::<Apples, u64>::from(7);
+    /// let y: AmountNoDefault<Apples, u64> = x.with_flags();
+    /// let z: Amount<Apples, u64> = y.with_flags();
+    /// assert_eq!(z, Amount::from(7));
+    /// ```
+    #[must_use]
+    pub fn with_flags<const TF2: TraitFlags>(self) -> Amount<TF2, Unit, Repr> {
+        Amount::new(self.0)
     }
-}
 
-#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<const TF: TraitFlags, Unit, Repr> Add for Amount<TF, Unit, Repr>
-where
-    Repr: AddAssign + Copy,
-{
-    type Output = Self;
-    fn add(mut self, rhs: Self) -> Self {
-        self.add_assign(rhs);
-        self
+    /// Like `display`, but with a context (like a locale) passed to the
+    /// [DisplayerWith] implementation of the unit.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use core::fmt;
+    /// use phantom_newtype::{Amount, DisplayerWith};
+    ///
+    /// struct Cents;
+    /// type Money = Amount<Cents, u64>;
+    ///
+    /// enum Locale {
+    ///     En,
+    ///     De,
+    /// }
+    ///
+    /// impl DisplayerWith<Money, Locale> for Cents {
+    ///     fn display_with(amount: &Money, locale: &Locale, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         let separator = match locale {
+    ///             Locale::En => '.',
+    ///             Locale::De => ',',
+    ///         };
+    ///         write!(f, "{}{}{:02}", amount.get() / 100, separator, amount.get() % 100)
+    ///     }
+    /// }
+    ///
+    /// let price = Money::from(1005);
+    /// assert_eq!(format!("{}", price.display_with(Locale::En)), "10.05");
+    /// assert_eq!(format!("{}", price.display_with(Locale::De)), "10,05");
+    /// ```
+    pub fn display_with<Ctx>(&self, ctx: Ctx) -> DisplayWithProxy<'_, Self, Ctx, Unit>
+    where
+        Unit: DisplayerWith<Self, Ctx>,
+    {
+        DisplayWithProxy::new(self, ctx)
     }
-}
 
-#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<const TF: TraitFlags, Unit, Repr> AddAssign for Amount<TF, Unit, Repr>
-where
-    Repr: AddAssign + Copy,
-{
-    fn add_assign(&mut self, rhs: Self) {
-        self.0 += rhs.get()
+    /// Formats like `Amount<Cents>(1005)` with [fmt::Debug], rather
+    /// than just `1005` as [fmt::Debug] of `Self` does. The unit name
+    /// comes from [core::any::type_name] (without its module path), so
+    /// it's for diagnostics only.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// struct Cents;
+    ///
+    /// let price = Amount::<Cents, u64>::from(1005);
+    /// assert_eq!(format!("{:?}", price.debug_with_unit()), "Amount<Cents>(1005)");
+    /// assert_eq!(format!("{:?}", price), "1005");
+    /// ```
+    pub fn debug_with_unit(&self) -> impl fmt::Debug + '_
+    where
+        Repr: fmt::Debug,
+    {
+        DebugWithUnit(self)
+    }
+
+    /// Displays the `Repr` followed by a space and the [UnitLabel] of
+    /// the unit, like `5 m`. Formatting options (like the width) apply
+    /// to the `Repr` only.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::{Amount, UnitLabel};
+    ///
+    /// enum Meters {}
+    /// impl UnitLabel for Meters {
+    ///     const LABEL: &'static str = "m";
+    /// }
+    ///
+    /// let distance = Amount::<Meters, u32>::from(5);
+    /// assert_eq!(format!("{}", distance.display_labeled()), "5 m");
+    /// assert_eq!(format!("{:.1}", Amount::<Meters, f64>::from(2.5).display_labeled()), "2.5 m");
+    /// assert_eq!(format!("{}", distance), "5");
+    /// ```
+    pub fn display_labeled(&self) -> impl fmt::Display + '_
+    where
+        Unit: UnitLabel,
+        Repr: fmt::Display,
+    {
+        DisplayLabeled(self)
     }
 }
 
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<const TF: TraitFlags, Unit, Repr> SubAssign for Amount<TF, Unit, Repr>
-where
-    Repr: SubAssign + Copy,
-{
-    fn sub_assign(&mut self, rhs: Self) {
-        self.0 -= rhs.get()
+impl<Unit, Repr> Amount<{ trait_flag::TRAIT_FLAGS_NO_COPY_IS_DEFAULT }, Unit, Repr> {
+    /// Converts to the `Copy` variant, keeping whether it's `Default`.
+    #[must_use]
+    pub fn into_copy(self) -> Amount<{ trait_flag::TRAIT_FLAGS_IS_COPY_IS_DEFAULT }, Unit, Repr> {
+        self.with_flags()
     }
 }
 
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<const TF: TraitFlags, Unit, Repr> Sub for Amount<TF, Unit, Repr>
-where
-    Repr: SubAssign + Copy,
-{
-    type Output = Self;
+impl<Unit, Repr> Amount<{ trait_flag::TRAIT_FLAGS_NO_COPY_NO_DEFAULT }, Unit, Repr> {
+    /// Converts to the `Copy` variant, keeping whether it's `Default`.
+    #[must_use]
+    pub fn into_copy(self) -> Amount<{ trait_flag::TRAIT_FLAGS_IS_COPY_NO_DEFAULT }, Unit, Repr> {
+        self.with_flags()
+    }
 
-    fn sub(mut self, rhs: Self) -> Self {
-        self.sub_assign(rhs);
-        self
+    /// `Repr::default()`, for the `*NoDefault` flags only. See
+    /// `AmountNoDefault::explicit_default`.
+    pub fn explicit_default() -> Self
+    where
+        Repr: Default,
+    {
+        Self::zeroed()
     }
 }
 
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<const TF: TraitFlags, Unit, Repr> MulAssign<Repr> for Amount<TF, Unit, Repr>
-where
-    Repr: MulAssign + Copy,
-{
-    fn mul_assign(&mut self, rhs: Repr) {
-        self.0 *= rhs;
+impl<Unit, Repr> Amount<{ trait_flag::TRAIT_FLAGS_IS_COPY_IS_DEFAULT }, Unit, Repr> {
+    /// Converts to the non-`Copy` variant, keeping whether it's `Default`.
+    #[must_use]
+    pub fn into_no_copy(
+        self,
+    ) -> Amount<{ trait_flag::TRAIT_FLAGS_NO_COPY_IS_DEFAULT }, Unit, Repr> {
+        self.with_flags()
     }
 }
 
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<const TF: TraitFlags, Unit, Repr> Mul<Repr> for Amount<TF, Unit, Repr>
-where
-    Repr: MulAssign + Copy,
-{
-    type Output = Self;
+impl<Unit, Repr> Amount<{ trait_flag::TRAIT_FLAGS_IS_COPY_NO_DEFAULT }, Unit, Repr> {
+    /// Converts to the non-`Copy` variant, keeping whether it's `Default`.
+    #[must_use]
+    pub fn into_no_copy(
+        self,
+    ) -> Amount<{ trait_flag::TRAIT_FLAGS_NO_COPY_NO_DEFAULT }, Unit, Repr> {
+        self.with_flags()
+    }
 
-    fn mul(mut self, rhs: Repr) -> Self {
-        self.mul_assign(rhs);
-        self
+    /// `Repr::default()`, for the `*NoDefault` flags only, where the
+    /// call site opts in explicitly (rather than [Default] being
+    /// implemented). The same as `zeroed`, but it doesn't compile for
+    /// the flags that have [Default] (use that instead).
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::AmountNoDefault;
+    ///
+    /// enum Cents {}
+    /// type Balance = AmountNoDefault<Cents, u64>;
+    ///
+    /// assert_eq!(Balance::explicit_default(), Balance::from(0));
+    /// ```
+    pub fn explicit_default() -> Self
+    where
+        Repr: Default,
+    {
+        Self::zeroed()
     }
 }
 
+#[cfg(feature = "alloc")]
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<const TF: TraitFlags, Unit, Repr> Div<Self> for Amount<TF, Unit, Repr>
+impl<const TF: TraitFlags, Unit, Repr> Amount<TF, Unit, Repr>
 where
-    Repr: Div<Repr> + Copy,
+    Repr: crate::ops::PrimitiveInteger + fmt::Display,
 {
-    type Output = <Repr as Div>::Output;
+    /// Formats the amount with `sep` between groups of three digits
+    /// (thousands).
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Cents {}
+    ///
+    /// assert_eq!(Amount::<Cents, u64>::from(1_000_000).to_grouped_string(','), "1,000,000");
+    /// assert_eq!(Amount::<Cents, i32>::from(-12_345).to_grouped_string('_'), "-12_345");
+    /// ```
+    pub fn to_grouped_string(&self, sep: char) -> alloc::string::String {
+        use alloc::string::{String, ToString};
 
-    fn div(self, rhs: Self) -> Self::Output {
-        self.0.div(rhs.0)
+        let plain = self.0.to_string();
+        let (sign, digits) = match plain.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", plain.as_str()),
+        };
+        let mut grouped = String::with_capacity(plain.len() + digits.len() / 3);
+        grouped.push_str(sign);
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                grouped.push(sep);
+            }
+            grouped.push(digit);
+        }
+        grouped
     }
 }
 
+// Deliberately a named function, not a `From` impl. See `Instant::from_amount`.
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<const TF: TraitFlags, Unit, Repr> fmt::Debug for Amount<TF, Unit, Repr>
-where
-    Repr: fmt::Debug,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self.0)
+impl<const TF: TraitFlags, Unit, Repr> Amount<TF, Unit, Repr> {
+    /// Reinterprets an instant (a point in time) as an amount (an
+    /// offset), that is, as the offset from the zero instant.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::{Amount, Instant};
+    ///
+    /// enum Seconds {}
+    ///
+    /// let since_epoch = Amount::from_instant(Instant::<Seconds, i64>::from(1_700_000_000));
+    /// assert_eq!(since_epoch, Amount::<Seconds, i64>::from(1_700_000_000));
+    /// ```
+    pub fn from_instant(instant: Instant<TF, Unit, Repr>) -> Self {
+        Self::new(instant.into_inner())
     }
 }
 
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<const TF: TraitFlags, Unit, Repr> fmt::Display for Amount<TF, Unit, Repr>
+impl<const TF: TraitFlags, Unit: Default, Repr> Amount<TF, Unit, Repr> {
+    // @TODO similar but without &self
+    //
+    /// Provides a useful shortcut to access units of an amount if
+    /// they implement the `Default` trait:
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// #[derive(Debug, Default)]
+    /// struct Seconds;
+    /// let duration = Amount::<Seconds, u64>::from(5);
+    ///
+    /// assert_eq!("5 Seconds", format!("{} {:?}", duration, duration.unit()));
+    /// ```
+    pub fn unit(&self) -> Unit {
+        Default::default()
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Amount<TF, Unit, Repr>
 where
-    Repr: fmt::Display,
+    Unit: DisplayerOf<Self>, //@TODO cleanup: Amount<TF, Unit, Repr>>,
 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+    /// `display` provides a mechanism to implement a custom display
+    /// for phantom types.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs),
+    /// )]
+    ///
+    /// use phantom_newtype::DisplayerOf;
+    /// use core::fmt;
+    ///
+    /// struct Cents;
+    /// struct YearUnit;
+    /// // This causes ICE (with feature `unstable_generic_const_own_type`), see https://github.com/rust-lang/rust/issues/134044:
+    /// #[cfg(not(feature = "unstable_generic_const_own_type"))]
+    /// type Money = phantom_newtype::Amount<Cents, u64>;
+    /// // No ICE:
+    /// #[cfg(feature = "unstable_generic_const_own_type")]
+    /// type Money = phantom_newtype::AmountForFlags<{phantom_newtype::trait_flag::TRAIT_FLAGS_IS_COPY_IS_DEFAULT}, Cents, u64>;
+    ///
+    /// impl DisplayerOf<Money> for Cents {
+    ///   fn display(amount: &Money, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///     write!(f, "${}.{:02}", amount.get() / 100, amount.get() % 100)
+    ///   }
+    /// }
+    ///
+    /// assert_eq!(format!("{}", Money::from(1005).display()), "$10.05");
+    /// ```
+    pub fn display(&self) -> DisplayProxy<'_, Self, Unit> {
+        DisplayProxy::new(self)
     }
 }
 
-// Derived serde `impl Serialize` produces an extra `unit` value for
-// phantom data, e.g. `Amount::<Meters>::from(10)` is serialized
-// into json as `[10, null]` by default.
-//
-// We want serialization format of `Repr` and the `Amount` to match
-// exactly, that's why we have to provide custom instances.
-#[cfg(feature = "serde")]
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<const TF: TraitFlags, Unit, Repr: Serialize> Serialize for Amount<TF, Unit, Repr> {
-    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        self.0.serialize(serializer)
+impl<const TF: TraitFlags, Unit, Repr: CheckedOps> Amount<TF, Unit, Repr> {
+    /// Checked addition. Returns `None` on overflow, rather than
+    /// panicking.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Cents {}
+    /// type Money = Amount<Cents, u64>;
+    ///
+    /// assert_eq!(Money::from(2).checked_add(Money::from(3)), Some(Money::from(5)));
+    /// assert_eq!(Money::from(u64::MAX).checked_add(Money::from(1)), None);
+    /// ```
+    ///
+    /// Like the other arithmetic methods, it doesn't modify `self`, and
+    /// it's `#[must_use]`. Discarding the result triggers the
+    /// `unused_must_use` lint:
+    ///
+    /// ```compile_fail
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    /// #![deny(unused_must_use)]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Cents {}
+    /// type Money = Amount<Cents, u64>;
+    ///
+    /// let balance = Money::from(2);
+    /// balance.checked_add(Money::from(3));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self::new)
+    }
+
+    /// Checked subtraction. Returns `None` on overflow (or underflow),
+    /// rather than panicking.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self::new)
+    }
+
+    /// Checked scaling by a scalar. Returns `None` on overflow, rather
+    /// than panicking.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_mul(self, rhs: Repr) -> Option<Self> {
+        self.0.checked_mul(rhs).map(Self::new)
+    }
+
+    /// Checked `self / rhs` (a ratio, without unit, like `Div<Self>`).
+    /// Returns `None` if `rhs` is zero (or on overflow, which is
+    /// `MIN / -1` only), rather than panicking.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Bytes {}
+    /// type Size = Amount<Bytes, u64>;
+    ///
+    /// assert_eq!(Size::from(10).checked_div(Size::from(3)), Some(3));
+    /// assert_eq!(Size::from(10).checked_rem(Size::from(3)), Some(1));
+    /// assert_eq!(Size::from(10).checked_div(Size::from(0)), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_div(self, rhs: Self) -> Option<Repr> {
+        self.0.checked_div(rhs.0)
+    }
+
+    /// Checked `self % rhs` (without unit, like `Rem<Self>`). Returns
+    /// `None` if `rhs` is zero (or on overflow), rather than panicking.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_rem(self, rhs: Self) -> Option<Repr> {
+        self.0.checked_rem(rhs.0)
+    }
+
+    /// Checked `+=`. Returns `false` (leaving `self` unchanged) on
+    /// overflow, and `true` otherwise.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Bytes {}
+    ///
+    /// let mut total = Amount::<Bytes, u8>::from(250);
+    /// assert!(total.checked_add_assign(Amount::from(5)));
+    /// assert!(!total.checked_add_assign(Amount::from(1)));
+    /// assert_eq!(total, 255);
+    /// ```
+    pub fn checked_add_assign(&mut self, rhs: Self) -> bool {
+        match self.0.checked_add(rhs.0) {
+            Some(sum) => {
+                self.0 = sum;
+                true
+            }
+            None => false,
+        }
     }
 }
 
-#[cfg(feature = "serde")]
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-impl<'de, const TF: TraitFlags, Unit, Repr> Deserialize<'de> for Amount<TF, Unit, Repr>
+impl<const TF: TraitFlags, Unit, Repr> Amount<TF, Unit, Repr>
+where
+    Repr: AddAssign + Copy,
+{
+    /// Applies `+=` and returns the new amount. Handy for updating
+    /// running totals in place, like values in a map.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Bytes {}
+    ///
+    /// let mut total = Amount::<Bytes, u64>::from(10);
+    /// assert_eq!(total.add_get(Amount::from(5)), 15);
+    /// assert_eq!(total, 15);
+    /// ```
+    pub fn add_get(&mut self, rhs: Self) -> Self {
+        self.0 += rhs.0;
+        Self::new(self.0)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: SaturatingOps> Amount<TF, Unit, Repr> {
+    /// Saturating addition. Clamps at the numeric bounds of `Repr`
+    /// instead of overflowing.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self::new(self.0.saturating_add(rhs.0))
+    }
+
+    /// Saturating subtraction. Clamps at the numeric bounds of `Repr`
+    /// instead of overflowing. For an unsigned `Repr` that means
+    /// clamping at zero:
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Apples {}
+    /// type NumApples = Amount<Apples, u64>;
+    ///
+    /// assert_eq!(NumApples::from(3).saturating_sub(NumApples::from(5)), NumApples::from(0));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self::new(self.0.saturating_sub(rhs.0))
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: WrappingOps> Amount<TF, Unit, Repr> {
+    /// Wrapping (modular) addition. Wraps around at the numeric bounds
+    /// of `Repr`.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Tick {}
+    /// type Ticks = Amount<Tick, u8>;
+    ///
+    /// assert_eq!(Ticks::from(255).wrapping_add(Ticks::from(1)), Ticks::from(0));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Self::new(self.0.wrapping_add(rhs.0))
+    }
+
+    /// Wrapping (modular) subtraction. Wraps around at the numeric
+    /// bounds of `Repr`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Self::new(self.0.wrapping_sub(rhs.0))
+    }
+
+    /// Wrapping (modular) scaling by a scalar. Wraps around at the
+    /// numeric bounds of `Repr`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn wrapping_mul(self, rhs: Repr) -> Self {
+        Self::new(self.0.wrapping_mul(rhs))
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: AbsDiffOps> Amount<TF, Unit, Repr> {
+    /// The (nonnegative) difference between two amounts, regardless of
+    /// their order. It never overflows: For an unsigned `Repr` the
+    /// result has the same `Repr`, and for a signed one it has the
+    /// unsigned `Repr` of the same width (as with the primitive
+    /// `abs_diff`).
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Meters {}
+    ///
+    /// let (a, b) = (Amount::<Meters, u64>::from(3), Amount::<Meters, u64>::from(10));
+    /// assert_eq!(a.abs_diff(b), Amount::from(7));
+    /// assert_eq!(b.abs_diff(a), Amount::from(7));
+    ///
+    /// let (a, b) = (Amount::<Meters, i8>::MIN, Amount::<Meters, i8>::MAX);
+    /// assert_eq!(a.abs_diff(b), Amount::<Meters, u8>::from(255));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn abs_diff(self, other: Self) -> Amount<TF, Unit, Repr::Output> {
+        Amount::new(self.0.abs_diff(other.0))
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: MidpointOps> Amount<TF, Unit, Repr> {
+    /// The average of two amounts, computed without intermediate
+    /// overflow. Like the primitive `midpoint` of `Repr`, it rounds down
+    /// for unsigned `Repr`, and towards zero for signed `Repr`.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Index {}
+    /// type Offset = Amount<Index, u64>;
+    ///
+    /// let (mut lo, mut hi) = (Offset::from(0), Offset::from(100));
+    /// while lo < hi {
+    ///     let mid = lo.midpoint(hi);
+    ///     if mid < Offset::from(42) {
+    ///         lo = mid + Offset::from(1);
+    ///     } else {
+    ///         hi = mid;
+    ///     }
+    /// }
+    /// assert_eq!(lo, Offset::from(42));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn midpoint(self, other: Self) -> Self {
+        Self::new(self.0.midpoint(other.0))
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Amount<TF, Unit, Repr>
+where
+    Range<Repr>: Iterator<Item = Repr>,
+{
+    /// Iterates over amounts from `start` (inclusive) to `end`
+    /// (exclusive), one by one, like `start..end` does for `Repr`.
+    /// (`core::iter::Step` can't be implemented on stable Rust, so
+    /// `Range<Amount>` is not iterable.)
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Row {}
+    /// type RowIndex = Amount<Row, u64>;
+    ///
+    /// let rows: Vec<RowIndex> = RowIndex::range(RowIndex::from(0), RowIndex::from(10)).collect();
+    /// assert_eq!(rows.len(), 10);
+    /// assert_eq!(rows.first(), Some(&RowIndex::from(0)));
+    /// assert_eq!(rows.last(), Some(&RowIndex::from(9)));
+    /// ```
+    pub fn range(start: Self, end: Self) -> impl Iterator<Item = Self> {
+        (start.0..end.0).map(Self::new)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Amount<TF, Unit, Repr>
+where
+    Repr: crate::ops::PrimitiveInteger,
+    Range<Repr>: DoubleEndedIterator<Item = Repr>,
+{
+    /// Iterates down from `self - 1` to zero (inclusive), like `(0..self).rev()` does for `Repr`.
+    /// Yields `self` amounts in total, and nothing if `self` is zero (or negative), so it never
+    /// underflows.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Retry {}
+    /// type Retries = Amount<Retry, u32>;
+    ///
+    /// let left: Vec<u32> = Retries::from(3).countdown().map(|r| r.get()).collect();
+    /// assert_eq!(left, [2, 1, 0]);
+    /// ```
+    pub fn countdown(self) -> impl Iterator<Item = Self> {
+        (Repr::ZERO..self.0).rev().map(Self::new)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: ScaleOps> Amount<TF, Unit, Repr> {
+    /// `self * num / den` (like applying a percentage), without
+    /// floating point. The product is computed in an integer type of
+    /// twice the width of `Repr` (like `u128` for `u64`), so it can't
+    /// overflow. The result is rounded towards zero. Not available for
+    /// 128-bit `Repr`s.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Cents {}
+    /// type Money = Amount<Cents, u64>;
+    ///
+    /// // 15% of $99.99, rounded down.
+    /// assert_eq!(Money::from(9_999).scale_by(15, 100), Money::from(1_499));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `den` is zero, or if the result overflows `Repr`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn scale_by(self, num: Repr, den: Repr) -> Self {
+        Self::new(self.0.scale_by(num, den))
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: UsizeOps> Amount<TF, Unit, Repr> {
+    /// Converts to `usize` (like for indexing a slice), failing if out
+    /// of its range. Whether that's possible depends on the target: A
+    /// `u64` always fits on 64-bit targets, but not on 32-bit ones.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Row {}
+    /// type RowIndex = Amount<Row, u64>;
+    ///
+    /// let rows = ["a", "b", "c"];
+    /// let index = RowIndex::from_usize(2);
+    /// assert_eq!(rows[index.try_into_usize().unwrap()], "c");
+    /// assert!(Amount::<Row, i32>::from(-1).try_into_usize().is_err());
+    /// ```
+    pub fn try_into_usize(self) -> Result<usize, TryFromIntError> {
+        self.0.try_into_usize()
+    }
+
+    /// Converts from `usize` (like a slice index or length).
+    ///
+    /// # Panics
+    ///
+    /// If `n` is out of the range of `Repr`.
+    pub fn from_usize(n: usize) -> Self {
+        Self::new(Repr::try_from_usize(n).expect("usize out of range of the amount's Repr"))
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: AbsOps> Amount<TF, Unit, Repr> {
+    /// The absolute value, in the same unit. Only for signed integer
+    /// and float `Repr`s.
+    ///
+    /// For integers, like the primitive `abs`, the absolute value of
+    /// `MIN` (like [i32::MIN]) doesn't fit: This panics in debug
+    /// builds, and returns `MIN` unchanged in release builds.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Temperature {}
+    ///
+    /// assert_eq!(Amount::<Temperature, i32>::from(-5).abs(), Amount::from(5));
+    /// assert_eq!(Amount::<Temperature, f64>::from(-0.5).abs(), Amount::from(0.5));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn abs(self) -> Self {
+        Self::new(self.0.abs())
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: SignedOps> Amount<TF, Unit, Repr> {
+    /// `-1`, `0` or `1` (in the same unit), depending on the sign of
+    /// `self`. Only for signed integer `Repr`s.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Balance {}
+    /// let overdrawn = Amount::<Balance, i64>::from(-250);
+    ///
+    /// assert_eq!(overdrawn.signum(), Amount::from(-1));
+    /// assert!(overdrawn.is_negative());
+    /// assert!(!overdrawn.is_positive());
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn signum(self) -> Self {
+        Self::new(self.0.signum())
+    }
+
+    /// Whether `self` is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    /// Whether `self` is greater than zero.
+    pub fn is_positive(&self) -> bool {
+        self.0.is_positive()
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: DivOps> Amount<TF, Unit, Repr> {
+    /// How many times `rhs` fits into `self`, rounded down (towards negative infinity). Unlike
+    /// `Div<Self>`, which rounds towards zero, like `/` does for `Repr`. (The two differ only for
+    /// signed `Repr` and operands of opposite signs.)
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Seconds {}
+    /// let span = Amount::<Seconds, u64>::from(23);
+    /// let window = Amount::from(5);
+    ///
+    /// assert_eq!(span.div_floor(window), 4);
+    /// assert_eq!(span.div_ceil(window), 5);
+    /// assert_eq!(span.div_rem(window), (4, Amount::from(3)));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn div_floor(self, rhs: Self) -> Repr {
+        self.0.div_floor(rhs.0)
+    }
+
+    /// How many times `rhs` fits into `self`, rounded up (towards positive infinity).
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn div_ceil(self, rhs: Self) -> Repr {
+        self.0.div_ceil(rhs.0)
+    }
+
+    /// The quotient as [Amount::div_floor] does, and the remainder, which is in the same unit (and
+    /// has the sign of `rhs`, or is zero).
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn div_rem(self, rhs: Self) -> (Repr, Self) {
+        let (quotient, remainder) = self.0.div_rem_floor(rhs.0);
+        (quotient, Self::new(remainder))
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: GcdOps> Amount<TF, Unit, Repr> {
+    /// The greatest common divisor, in the same unit. For integer `Repr`s. For signed ones, it's
+    /// non-negative, and it overflows for `MIN` and zero (or `MIN` and `MIN`): See
+    /// [GcdOps::gcd].
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Ticks {}
+    /// type Period = Amount<Ticks, u64>;
+    ///
+    /// assert_eq!(Period::from(12).gcd(Period::from(18)), Period::from(6));
+    /// assert_eq!(Period::from(12).lcm(Period::from(18)), Period::from(36));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn gcd(self, other: Self) -> Self {
+        Self::new(self.0.gcd(other.0))
+    }
+
+    /// The least common multiple, in the same unit (like the period after which two periodic
+    /// events coincide again). It's zero if either is zero, and non-negative for signed `Repr`s.
+    /// See [GcdOps::lcm] for overflow.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn lcm(self, other: Self) -> Self {
+        Self::new(self.0.lcm(other.0))
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: PartialOrd> Amount<TF, Unit, Repr> {
+    /// Whether the amount is within `range`, which can be any kind of
+    /// range (half-open, closed, unbounded...).
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Percent {}
+    /// type Percentage = Amount<Percent, u8>;
+    ///
+    /// let (lo, hi) = (Percentage::from(0), Percentage::from(100));
+    /// assert!(Percentage::from(100).is_in_range(lo..=hi));
+    /// assert!(!Percentage::from(100).is_in_range(lo..hi));
+    /// assert!(!Percentage::from(101).is_in_range(..=hi));
+    /// ```
+    pub fn is_in_range(&self, range: impl RangeBounds<Self>) -> bool {
+        range.contains(self)
+    }
+}
+
+// Dimensional escape hatches: Amounts of different units don't divide
+// (or multiply) by default, but the caller can name the resulting unit.
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Amount<TF, Unit, Repr> {
+    /// Divides by an amount of another unit, resulting in an amount of
+    /// `ResultUnit` (named by the caller). The caller is responsible
+    /// for the dimensional correctness (like bytes / seconds = bytes
+    /// per second).
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Bytes {}
+    /// enum Seconds {}
+    /// enum BytesPerSecond {}
+    ///
+    /// let transferred = Amount::<Bytes, u64>::from(10_000);
+    /// let elapsed = Amount::<Seconds, u64>::from(4);
+    /// let rate = transferred.div_as::<BytesPerSecond, _>(elapsed);
+    /// assert_eq!(rate, Amount::<BytesPerSecond, u64>::from(2_500));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn div_as<ResultUnit, OtherUnit>(
+        self,
+        rhs: Amount<TF, OtherUnit, Repr>,
+    ) -> Amount<TF, ResultUnit, Repr>
+    where
+        Repr: Div<Output = Repr>,
+    {
+        Amount::new(self.0 / rhs.0)
+    }
+
+    /// Multiplies by an amount of (possibly) another unit, resulting in
+    /// an amount of `ResultUnit` (named by the caller). The caller is
+    /// responsible for the dimensional correctness (like meters *
+    /// meters = square meters).
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Meters {}
+    /// enum SquareMeters {}
+    ///
+    /// let side = Amount::<Meters, u64>::from(7);
+    /// assert_eq!(side.mul_as::<SquareMeters, _>(side), Amount::<SquareMeters, u64>::from(49));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn mul_as<ResultUnit, OtherUnit>(
+        self,
+        rhs: Amount<TF, OtherUnit, Repr>,
+    ) -> Amount<TF, ResultUnit, Repr>
+    where
+        Repr: Mul<Output = Repr>,
+    {
+        Amount::new(self.0 * rhs.0)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: Ord> Amount<TF, Unit, Repr> {
+    /// Restricts the amount to `lo..=hi`, like [Ord::clamp], but
+    /// without having to import [Ord].
+    ///
+    /// # Panics
+    ///
+    /// If `lo > hi`.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Percent {}
+    /// type Percentage = Amount<Percent, u8>;
+    ///
+    /// let (lo, hi) = (Percentage::from(0), Percentage::from(100));
+    /// assert_eq!(Percentage::from(150).clamp(lo, hi), hi);
+    /// assert_eq!(Percentage::from(42).clamp(lo, hi), Percentage::from(42));
+    /// ```
+    ///
+    /// ```should_panic
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Amount;
+    ///
+    /// enum Percent {}
+    /// type Percentage = Amount<Percent, u8>;
+    ///
+    /// Percentage::from(50).clamp(Percentage::from(100), Percentage::from(0));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn clamp(self, lo: Self, hi: Self) -> Self {
+        Ord::clamp(self, lo, hi)
+    }
+
+    /// The smaller of the two amounts, like [Ord::min].
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+
+    /// The larger of the two amounts, like [Ord::max].
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+}
+
+macro_rules! impl_bounds {
+    ($($t:ty)*) => {$(
+        #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+        impl<const TF: TraitFlags, Unit> Amount<TF, Unit, $t> {
+            /// The smallest amount representable by `Repr`.
+            pub const MIN: Self = Self::new(<$t>::MIN);
+            /// The largest amount representable by `Repr`.
+            pub const MAX: Self = Self::new(<$t>::MAX);
+        }
+    )*};
+}
+
+impl_bounds! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+// `Add`, `Mul` and comparisons can't be `const` (on stable), hence inherent `const fn`s, per
+// primitive `Repr`.
+macro_rules! impl_const_ops {
+    ($($t:ty)*) => {$(
+        #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+        impl<const TF: TraitFlags, Unit> Amount<TF, Unit, $t> {
+            /// Like `self + rhs`, but usable in `const` context. It
+            /// overflows like `+` does (in `const` context that fails
+            /// to compile).
+            ///
+            /// ```
+            /// #![cfg_attr(
+            ///     feature = "unstable_generic_const_own_type",
+            ///     feature(generic_const_exprs)
+            /// )]
+            ///
+            /// use phantom_newtype::Amount;
+            ///
+            /// enum Cents {}
+            /// type Money = Amount<Cents, u64>;
+            ///
+            /// const PRICE: Money = Money::new(1_000);
+            /// const SHIPPING: Money = Money::new(250);
+            /// const TOTAL: Money = PRICE.add_const(SHIPPING);
+            /// const BULK: Money = PRICE.mul_const(12);
+            /// assert_eq!(TOTAL, Money::from(1_250));
+            /// assert_eq!(BULK, Money::from(12_000));
+            /// ```
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn add_const(self, rhs: Self) -> Self {
+                Self::new(self.0 + rhs.0)
+            }
+
+            /// Like `self * by`, but usable in `const` context. It
+            /// overflows like `*` does.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn mul_const(self, by: $t) -> Self {
+                Self::new(self.0 * by)
+            }
+
+            /// Like `self == other`, but usable in `const` context.
+            ///
+            /// ```
+            /// #![cfg_attr(
+            ///     feature = "unstable_generic_const_own_type",
+            ///     feature(generic_const_exprs)
+            /// )]
+            ///
+            /// use phantom_newtype::Amount;
+            ///
+            /// enum Celsius {}
+            /// type Temperature = Amount<Celsius, i16>;
+            ///
+            /// const MIN: Temperature = Temperature::new(-40);
+            /// const MAX: Temperature = Temperature::new(85);
+            /// const VALID_RANGE: bool = MIN.const_lt(&MAX) && !MIN.const_eq(&MAX);
+            /// assert!(VALID_RANGE);
+            /// ```
+            pub const fn const_eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+
+            /// Like `self < other`, but usable in `const` context.
+            pub const fn const_lt(&self, other: &Self) -> bool {
+                self.0 < other.0
+            }
+        }
+    )*};
+}
+
+impl_const_ops! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> AsRef<Repr> for Amount<TF, Unit, Repr> {
+    fn as_ref(&self) -> &Repr {
+        &self.0
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> From<Repr> for Amount<TF, Unit, Repr> {
+    fn from(repr: Repr) -> Self {
+        Self::new(repr)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: FromStr> FromStr for Amount<TF, Unit, Repr> {
+    type Err = Repr::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Repr::from_str(s).map(Self::new)
+    }
+}
+
+// Note that we only have to write the boilerplate trait
+// implementation below because default implementations of traits put
+// unnecessary restrictions on the type parameters. E.g. deriving
+// `PartialEq<Wrapper<T>>` require `T` to implement `PartialEq`, which
+// is not what we want: `T` is phantom in our case.
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: Clone> Clone for Amount<TF, Unit, Repr> {
+    fn clone(&self) -> Self {
+        Amount(self.0.clone(), PhantomData)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<Unit, Repr: Copy> Copy for Amount<{ trait_flag::TRAIT_FLAGS_IS_COPY_IS_DEFAULT }, Unit, Repr> {}
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<Unit, Repr: Copy> Copy for Amount<{ trait_flag::TRAIT_FLAGS_IS_COPY_NO_DEFAULT }, Unit, Repr> {}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<Unit, Repr: Default> Default
+    for Amount<{ trait_flag::TRAIT_FLAGS_IS_COPY_IS_DEFAULT }, Unit, Repr>
+{
+    fn default() -> Self {
+        Self(Default::default(), PhantomData)
+    }
+}
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<Unit, Repr: Default> Default
+    for Amount<{ trait_flag::TRAIT_FLAGS_NO_COPY_IS_DEFAULT }, Unit, Repr>
+{
+    fn default() -> Self {
+        Self(Default::default(), PhantomData)
+    }
+}
+
+/// Compares amounts of the same unit, even if they differ in trait
+/// flags (like a `Copy` amount from one subsystem and a non-`Copy` one
+/// from another).
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::{Amount, AmountNoCopy};
+///
+/// enum Apples {}
+///
+/// assert!(Amount::<Apples, u64>::from(7) == AmountNoCopy::<Apples, u64>::from(7));
+/// assert!(AmountNoCopy::<Apples, u64>::from(7) != Amount::<Apples, u64>::from(8));
+/// ```
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, const TF2: TraitFlags, Unit, Repr: PartialEq>
+    PartialEq<Amount<TF2, Unit, Repr>> for Amount<TF, Unit, Repr>
+{
+    fn eq(&self, rhs: &Amount<TF2, Unit, Repr>) -> bool {
+        self.0.eq(&rhs.0)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: PartialEq> Amount<TF, Unit, Repr> {
+    /// Same as `==` (which compares across trait flags); mirrors
+    /// `Id::same_value`.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::{Amount, AmountNoCopy};
+    ///
+    /// enum Apples {}
+    ///
+    /// let copy = Amount::<Apples, u64>::from(7);
+    /// let no_copy = AmountNoCopy::<Apples, u64>::from(7);
+    /// assert!(copy.same_value(&no_copy));
+    /// assert!(!copy.same_value(&AmountNoCopy::from(8)));
+    /// ```
+    pub fn same_value<const TF2: TraitFlags>(&self, other: &Amount<TF2, Unit, Repr>) -> bool {
+        self == other
+    }
+}
+
+/// Compares with a bare `Repr`. The other direction (`Repr == Amount`)
+/// is implemented for primitive numeric `Repr`s only, because of the
+/// orphan rules.
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::Amount;
+///
+/// enum Apples {}
+///
+/// let apples = Amount::<Apples, u64>::from(5);
+/// assert!(apples == 5);
+/// assert!(5 == apples);
+/// assert_eq!(apples, 5);
+/// assert_ne!(6, apples);
+/// ```
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: PartialEq> PartialEq<Repr> for Amount<TF, Unit, Repr> {
+    fn eq(&self, rhs: &Repr) -> bool {
+        self.0.eq(rhs)
+    }
+}
+
+/// Compares with a bare `Repr`, consistently with the ordering of
+/// `Repr`. Like with [PartialEq], the other direction is implemented
+/// for primitive numeric `Repr`s only.
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::Amount;
+///
+/// enum Bytes {}
+///
+/// let size = Amount::<Bytes, u64>::from(4096);
+/// assert!(size > 1024);
+/// assert!(1024 < size);
+/// assert!(size <= 4096);
+/// assert!(!(8192 <= size));
+/// ```
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: PartialOrd> PartialOrd<Repr> for Amount<TF, Unit, Repr> {
+    fn partial_cmp(&self, rhs: &Repr) -> Option<Ordering> {
+        self.0.partial_cmp(rhs)
+    }
+}
+
+macro_rules! impl_repr_cmp_amount {
+    ($($t:ty)*) => {$(
+        #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+        impl<const TF: TraitFlags, Unit> PartialEq<Amount<TF, Unit, $t>> for $t {
+            fn eq(&self, rhs: &Amount<TF, Unit, $t>) -> bool {
+                self.eq(&rhs.0)
+            }
+        }
+
+        #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+        impl<const TF: TraitFlags, Unit> PartialOrd<Amount<TF, Unit, $t>> for $t {
+            fn partial_cmp(&self, rhs: &Amount<TF, Unit, $t>) -> Option<Ordering> {
+                self.partial_cmp(&rhs.0)
+            }
+        }
+    )*};
+}
+
+impl_repr_cmp_amount! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64 }
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: Eq> Eq for Amount<TF, Unit, Repr> {}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: PartialOrd> PartialOrd for Amount<TF, Unit, Repr> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&rhs.0)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: Ord> Ord for Amount<TF, Unit, Repr> {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.0.cmp(&rhs.0)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: Hash> Hash for Amount<TF, Unit, Repr> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Add for Amount<TF, Unit, Repr>
+where
+    Repr: AddAssign + Copy,
+{
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self {
+        self.add_assign(rhs);
+        self
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> AddAssign for Amount<TF, Unit, Repr>
+where
+    Repr: AddAssign + Copy,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.get()
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> SubAssign for Amount<TF, Unit, Repr>
+where
+    Repr: SubAssign + Copy,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.get()
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Sub for Amount<TF, Unit, Repr>
+where
+    Repr: SubAssign + Copy,
+{
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self {
+        self.sub_assign(rhs);
+        self
+    }
+}
+
+// Adding or subtracting a bare `Repr` (a delta of the same unit), keeping the unit.
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> AddAssign<Repr> for Amount<TF, Unit, Repr>
+where
+    Repr: AddAssign,
+{
+    fn add_assign(&mut self, rhs: Repr) {
+        self.0 += rhs
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Add<Repr> for Amount<TF, Unit, Repr>
+where
+    Repr: AddAssign,
+{
+    type Output = Self;
+
+    fn add(mut self, rhs: Repr) -> Self {
+        self.add_assign(rhs);
+        self
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> SubAssign<Repr> for Amount<TF, Unit, Repr>
+where
+    Repr: SubAssign,
+{
+    fn sub_assign(&mut self, rhs: Repr) {
+        self.0 -= rhs
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Sub<Repr> for Amount<TF, Unit, Repr>
+where
+    Repr: SubAssign,
+{
+    type Output = Self;
+
+    fn sub(mut self, rhs: Repr) -> Self {
+        self.sub_assign(rhs);
+        self
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Neg for Amount<TF, Unit, Repr>
+where
+    Repr: Neg<Output = Repr>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(self.0.neg())
+    }
+}
+
+// Bitwise operations between amounts of the same unit (like masks of a register), keeping the
+// unit.
+macro_rules! impl_bit_ops {
+    ($($op:ident::$op_fn:ident, $op_assign:ident::$op_assign_fn:ident);*) => {$(
+        #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+        impl<const TF: TraitFlags, Unit, Repr> $op for Amount<TF, Unit, Repr>
+        where
+            Repr: $op<Output = Repr>,
+        {
+            type Output = Self;
+
+            fn $op_fn(self, rhs: Self) -> Self {
+                Self::new(self.0.$op_fn(rhs.0))
+            }
+        }
+
+        #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+        impl<const TF: TraitFlags, Unit, Repr> $op_assign for Amount<TF, Unit, Repr>
+        where
+            Repr: $op_assign,
+        {
+            fn $op_assign_fn(&mut self, rhs: Self) {
+                self.0.$op_assign_fn(rhs.0)
+            }
+        }
+    )*};
+}
+
+impl_bit_ops! {
+    BitAnd::bitand, BitAndAssign::bitand_assign;
+    BitOr::bitor, BitOrAssign::bitor_assign;
+    BitXor::bitxor, BitXorAssign::bitxor_assign
+}
+
+/// Bitwise negation, keeping the unit.
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::Amount;
+///
+/// enum StatusReg {}
+/// type Status = Amount<StatusReg, u32>;
+///
+/// const READY: Status = Status::new(0b001);
+/// const ERROR: Status = Status::new(0b100);
+///
+/// let mut status = READY | ERROR;
+/// assert_eq!(status, Status::from(0b101));
+/// assert_eq!(status & ERROR, ERROR);
+///
+/// status &= !ERROR;
+/// assert_eq!(status, READY);
+/// status ^= READY;
+/// assert_eq!(status, Status::from(0));
+/// ```
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Not for Amount<TF, Unit, Repr>
+where
+    Repr: Not<Output = Repr>,
+{
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::new(self.0.not())
+    }
+}
+
+// Shifts by a number of bits (not by an amount), keeping the unit. Like for the primitives,
+// shifting by at least the bit width of `Repr` panics in debug builds (and masks the shift in
+// release builds), and bits shifted out are lost.
+macro_rules! impl_shift_ops {
+    ($($op:ident::$op_fn:ident, $op_assign:ident::$op_assign_fn:ident);*) => {$(
+        #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+        impl<const TF: TraitFlags, Unit, Repr> $op<u32> for Amount<TF, Unit, Repr>
+        where
+            Repr: $op<u32, Output = Repr>,
+        {
+            type Output = Self;
+
+            fn $op_fn(self, bits: u32) -> Self {
+                Self::new(self.0.$op_fn(bits))
+            }
+        }
+
+        #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+        impl<const TF: TraitFlags, Unit, Repr> $op_assign<u32> for Amount<TF, Unit, Repr>
+        where
+            Repr: $op_assign<u32>,
+        {
+            fn $op_assign_fn(&mut self, bits: u32) {
+                self.0.$op_assign_fn(bits)
+            }
+        }
+    )*};
+}
+
+impl_shift_ops! {
+    Shl::shl, ShlAssign::shl_assign;
+    Shr::shr, ShrAssign::shr_assign
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Sum for Amount<TF, Unit, Repr>
+where
+    Repr: AddAssign + Default + Copy,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(Repr::default()), Add::add)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<'a, const TF: TraitFlags, Unit, Repr> Sum<&'a Self> for Amount<TF, Unit, Repr>
+where
+    Repr: AddAssign + Default + Copy,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::new(Repr::default()), |acc, x| acc.add(Self::new(x.0)))
+    }
+}
+
+/// The smallest of the amounts, compared by their `Repr`s, or [None] if
+/// there are none. If several are equally small, this returns the first
+/// one. (The same as [Iterator::min], but without having to know that
+/// `Ord` of [Amount](crate::Amount) is that of `Repr`.)
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::{max_by_repr, min_by_repr, Amount};
+///
+/// enum Millis {}
+/// type Latency = Amount<Millis, u32>;
+///
+/// let samples = [Latency::from(12), Latency::from(3), Latency::from(40)];
+/// assert_eq!(min_by_repr(samples.iter().copied()), Some(Latency::from(3)));
+/// assert_eq!(max_by_repr(samples.iter().copied()), Some(Latency::from(40)));
+/// assert_eq!(min_by_repr(core::iter::empty::<Latency>()), None);
+/// ```
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+pub fn min_by_repr<const TF: TraitFlags, Unit, Repr: Ord>(
+    amounts: impl IntoIterator<Item = Amount<TF, Unit, Repr>>,
+) -> Option<Amount<TF, Unit, Repr>> {
+    amounts.into_iter().min_by(|a, b| a.0.cmp(&b.0))
+}
+
+/// The greatest of the amounts, compared by their `Repr`s, or [None] if
+/// there are none. If several are equally great, this returns the last
+/// one (like [Iterator::max]).
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+pub fn max_by_repr<const TF: TraitFlags, Unit, Repr: Ord>(
+    amounts: impl IntoIterator<Item = Amount<TF, Unit, Repr>>,
+) -> Option<Amount<TF, Unit, Repr>> {
+    amounts.into_iter().max_by(|a, b| a.0.cmp(&b.0))
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> MulAssign<Repr> for Amount<TF, Unit, Repr>
+where
+    Repr: MulAssign + Copy,
+{
+    fn mul_assign(&mut self, rhs: Repr) {
+        self.0 *= rhs;
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Mul<Repr> for Amount<TF, Unit, Repr>
+where
+    Repr: MulAssign + Copy,
+{
+    type Output = Self;
+
+    fn mul(mut self, rhs: Repr) -> Self {
+        self.mul_assign(rhs);
+        self
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Div<Self> for Amount<TF, Unit, Repr>
+where
+    Repr: Div<Repr> + Copy,
+{
+    type Output = <Repr as Div>::Output;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.0.div(rhs.0)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> DivAssign<Repr> for Amount<TF, Unit, Repr>
+where
+    Repr: DivAssign + Copy,
+{
+    fn div_assign(&mut self, rhs: Repr) {
+        self.0 /= rhs;
+    }
+}
+
+// Unlike `Div<Self>` above, dividing by a scalar keeps the unit.
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Div<Repr> for Amount<TF, Unit, Repr>
+where
+    Repr: DivAssign + Copy,
+{
+    type Output = Self;
+
+    fn div(mut self, rhs: Repr) -> Self {
+        self.div_assign(rhs);
+        self
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Rem<Self> for Amount<TF, Unit, Repr>
+where
+    Repr: Rem<Repr> + Copy,
+{
+    type Output = <Repr as Rem>::Output;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.0.rem(rhs.0)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> fmt::Debug for Amount<TF, Unit, Repr>
+where
+    Repr: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+struct DebugWithUnit<'a, const TF: TraitFlags, Unit, Repr>(&'a Amount<TF, Unit, Repr>);
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: fmt::Debug> fmt::Debug
+    for DebugWithUnit<'_, TF, Unit, Repr>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Amount<{}>(", short_type_name::<Unit>())?;
+        fmt::Debug::fmt(&self.0 .0, f)?;
+        f.write_str(")")
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+struct DisplayLabeled<'a, const TF: TraitFlags, Unit, Repr>(&'a Amount<TF, Unit, Repr>);
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit: UnitLabel, Repr: fmt::Display> fmt::Display
+    for DisplayLabeled<'_, TF, Unit, Repr>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0 .0, f)?;
+        write!(f, " {}", Unit::LABEL)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> fmt::Display for Amount<TF, Unit, Repr>
+where
+    Repr: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: fmt::LowerHex> fmt::LowerHex for Amount<TF, Unit, Repr> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: fmt::UpperHex> fmt::UpperHex for Amount<TF, Unit, Repr> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: fmt::Octal> fmt::Octal for Amount<TF, Unit, Repr> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Octal::fmt(&self.0, f)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: fmt::Binary> fmt::Binary for Amount<TF, Unit, Repr> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(&self.0, f)
+    }
+}
+
+// `num_traits::One` is not implemented on purpose: it requires `Mul<Self>`,
+// and multiplying amounts doesn't give an amount of the same unit.
+#[cfg(feature = "num-traits")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Zero for Amount<TF, Unit, Repr>
+where
+    Repr: Zero + AddAssign + Copy,
+{
+    fn zero() -> Self {
+        Self::new(Repr::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+// SAFETY: `Amount` is `#[repr(transparent)]` over `Repr`. Its only other
+// field is `PhantomData<AtomicPtr<Unit>>`, which is zero-sized, has
+// alignment 1 and no bytes. Hence `Amount` has the same layout and the same
+// valid bit patterns as `Repr`: all-zeros is valid iff it's valid for `Repr`.
+#[cfg(feature = "bytemuck")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+unsafe impl<const TF: TraitFlags, Unit, Repr: bytemuck::Zeroable> bytemuck::Zeroable
+    for Amount<TF, Unit, Repr>
+{
+}
+
+// SAFETY: See `Zeroable` above. Additionally, `Amount` has no padding (as
+// `Repr: Pod` has none), `Self: Copy` (required by `Pod`) holds only for the
+// `*IsCopy*` flags, and `Unit: 'static` makes `Amount` `'static`.
+#[cfg(feature = "bytemuck")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+unsafe impl<const TF: TraitFlags, Unit: 'static, Repr: bytemuck::Pod> bytemuck::Pod
+    for Amount<TF, Unit, Repr>
+where
+    Self: Copy,
+{
+}
+
+// Widening conversions between amounts of the same unit. A generic
+// `impl<Small, Big: From<Small>> From<Amount<.., Small>> for Amount<.., Big>`
+// would conflict with core's blanket `impl<T> From<T> for T` (when
+// `Small == Big`), hence we implement it for pairs of primitive integers
+// where core has `From`. (`TryFrom` for those pairs then comes from core's
+// blanket.)
+macro_rules! impl_from_amount {
+    ($($from:ty => $($to:ty)*;)*) => {$($(
+        #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+        impl<const TF: TraitFlags, Unit> From<Amount<TF, Unit, $from>> for Amount<TF, Unit, $to> {
+            fn from(amount: Amount<TF, Unit, $from>) -> Self {
+                Self::new(<$to>::from(amount.0))
+            }
+        }
+    )*)*};
+}
+
+impl_from_amount! {
+    u8 => u16 u32 u64 u128 usize i16 i32 i64 i128 isize;
+    u16 => u32 u64 u128 usize i32 i64 i128;
+    u32 => u64 u128 i64 i128;
+    u64 => u128 i128;
+    i8 => i16 i32 i64 i128 isize;
+    i16 => i32 i64 i128 isize;
+    i32 => i64 i128;
+    i64 => i128;
+}
+
+// Narrowing conversions between amounts of the same unit. A generic
+// `impl<R1, R2: TryFrom<R1>> TryFrom<Amount<.., R1>> for Amount<.., R2>`
+// would conflict with core's blanket `impl<T, U: Into<T>> TryFrom<U> for T`
+// (when `R1 == R2`), hence we implement it for pairs of primitive integers
+// only, and only where core has no `From` between them.
+macro_rules! impl_try_from_amount {
+    ($($from:ty => $($to:ty)*;)*) => {$($(
+        #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+        impl<const TF: TraitFlags, Unit> TryFrom<Amount<TF, Unit, $from>>
+            for Amount<TF, Unit, $to>
+        {
+            type Error = <$to as TryFrom<$from>>::Error;
+
+            fn try_from(amount: Amount<TF, Unit, $from>) -> Result<Self, Self::Error> {
+                <$to>::try_from(amount.0).map(Self::new)
+            }
+        }
+    )*)*};
+}
+
+impl_try_from_amount! {
+    u8 => i8;
+    u16 => u8 i8 i16 isize;
+    u32 => u8 u16 usize i8 i16 i32 isize;
+    u64 => u8 u16 u32 usize i8 i16 i32 i64 isize;
+    u128 => u8 u16 u32 u64 usize i8 i16 i32 i64 i128 isize;
+    usize => u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 isize;
+    i8 => u8 u16 u32 u64 u128 usize;
+    i16 => u8 u16 u32 u64 u128 usize i8;
+    i32 => u8 u16 u32 u64 u128 usize i8 i16 isize;
+    i64 => u8 u16 u32 u64 u128 usize i8 i16 i32 isize;
+    i128 => u8 u16 u32 u64 u128 usize i8 i16 i32 i64 isize;
+    isize => u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128;
+}
+
+// Derived serde `impl Serialize` produces an extra `unit` value for
+// phantom data, e.g. `Amount::<Meters>::from(10)` is serialized
+// into json as `[10, null]` by default.
+//
+// We want serialization format of `Repr` and the `Amount` to match
+// exactly, that's why we have to provide custom instances.
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: Serialize> Serialize for Amount<TF, Unit, Repr> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<'de, const TF: TraitFlags, Unit, Repr> Deserialize<'de> for Amount<TF, Unit, Repr>
 where
     Repr: Deserialize<'de>,
 {
-    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        Repr::deserialize(deserializer).map(Self::new)
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Repr::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<'a, const TF: TraitFlags, Unit: 'a, Repr> arbitrary::Arbitrary<'a> for Amount<TF, Unit, Repr>
+where
+    Repr: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Repr::arbitrary(u).map(Self::new)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Repr::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "proptest")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> proptest::arbitrary::Arbitrary for Amount<TF, Unit, Repr>
+where
+    Repr: proptest::arbitrary::Arbitrary,
+{
+    type Parameters = Repr::Parameters;
+    type Strategy = proptest::strategy::Map<Repr::Strategy, fn(Repr) -> Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+        Repr::arbitrary_with(args).prop_map(Self::new)
+    }
+}
+
+/// Generates amounts whose `Repr`s come from the given strategy. Use it to
+/// bound the generated magnitudes, e.g. `amount_in_range(0u64..1_000)`.
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// #[cfg(feature = "proptest")] {
+/// use phantom_newtype::{amount_in_range, Amount};
+/// use proptest::prelude::*;
+///
+/// enum Meters {}
+/// type Distance = Amount<Meters, u64>;
+///
+/// proptest!(|(a in amount_in_range(0u64..1 << 32), b in amount_in_range(0u64..1 << 32))| {
+///     let (a, b): (Distance, Distance) = (a, b);
+///     prop_assert_eq!((a + b) - b, a);
+/// });
+/// }
+/// ```
+#[cfg(feature = "proptest")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+pub fn amount_in_range<const TF: TraitFlags, Unit, Repr: fmt::Debug>(
+    repr: impl proptest::strategy::Strategy<Value = Repr>,
+) -> impl proptest::strategy::Strategy<Value = Amount<TF, Unit, Repr>> {
+    repr.prop_map(Amount::new)
+}
+
+#[cfg(feature = "quickcheck")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit: 'static, Repr> quickcheck::Arbitrary for Amount<TF, Unit, Repr>
+where
+    Repr: quickcheck::Arbitrary,
+{
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Self::new(Repr::arbitrary(g))
+    }
+
+    fn shrink(&self) -> alloc::boxed::Box<dyn Iterator<Item = Self>> {
+        alloc::boxed::Box::new(self.0.shrink().map(Self::new))
+    }
+}
+
+// Like with `serde`, the encoded forms of `Amount` and `Repr` are identical.
+#[cfg(feature = "borsh")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> borsh::BorshSerialize for Amount<TF, Unit, Repr>
+where
+    Repr: borsh::BorshSerialize,
+{
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> borsh::BorshDeserialize for Amount<TF, Unit, Repr>
+where
+    Repr: borsh::BorshDeserialize,
+{
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        Repr::deserialize_reader(reader).map(Self::new)
+    }
+}
+
+// The archived form of `Amount<TF, Unit, Repr>` is `Amount<TF, Unit,
+// Archived<Repr>>`. Its bytes are exactly those of `Archived<Repr>` (the unit
+// doesn't appear in the archive), but it still carries `Unit`, so accessing
+// an archive stays type-safe.
+//
+// SAFETY: `Amount` is `#[repr(transparent)]` over `Repr` (the other field is a
+// zero-sized `PhantomData`), hence it's as portable as `Repr` is.
+#[cfg(feature = "rkyv")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+unsafe impl<const TF: TraitFlags, Unit, Repr: rkyv::Portable> rkyv::Portable
+    for Amount<TF, Unit, Repr>
+{
+}
+
+// SAFETY: `Amount` is `#[repr(transparent)]` over `Repr`, so a pointer to
+// `Amount` is a valid pointer to `Repr`, and their valid bit patterns are the
+// same.
+#[cfg(feature = "rkyv")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+unsafe impl<const TF: TraitFlags, Unit, Repr, C> rkyv::bytecheck::CheckBytes<C>
+    for Amount<TF, Unit, Repr>
+where
+    Repr: rkyv::bytecheck::CheckBytes<C>,
+    C: rkyv::rancor::Fallible + ?Sized,
+{
+    unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+        Repr::check_bytes(value.cast::<Repr>(), context)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: rkyv::Archive> rkyv::Archive for Amount<TF, Unit, Repr> {
+    type Archived = Amount<TF, Unit, rkyv::Archived<Repr>>;
+    type Resolver = rkyv::Resolver<Repr>;
+
+    fn resolve(&self, resolver: Self::Resolver, out: rkyv::Place<Self::Archived>) {
+        // SAFETY: `Self::Archived` is `#[repr(transparent)]` over
+        // `Archived<Repr>`, so the place points to a valid `Archived<Repr>`.
+        let out = unsafe { out.cast_unchecked::<rkyv::Archived<Repr>>() };
+        self.0.resolve(resolver, out);
+    }
+}
+
+#[cfg(feature = "rkyv")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr, S> rkyv::Serialize<S> for Amount<TF, Unit, Repr>
+where
+    Repr: rkyv::Serialize<S>,
+    S: rkyv::rancor::Fallible + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr, D> rkyv::Deserialize<Amount<TF, Unit, Repr>, D>
+    for Amount<TF, Unit, rkyv::Archived<Repr>>
+where
+    Repr: rkyv::Archive,
+    rkyv::Archived<Repr>: rkyv::Deserialize<Repr, D>,
+    D: rkyv::rancor::Fallible + ?Sized,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<Amount<TF, Unit, Repr>, D::Error> {
+        self.0.deserialize(deserializer).map(Amount::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use core::convert::{TryFrom, TryInto};
+
+    #[test]
+    fn test_neg() {
+        enum Debt {}
+        enum Celsius {}
+
+        assert_eq!(-Amount::<Debt, i64>::from(5), Amount::from(-5));
+        assert_eq!(-Amount::<Debt, i64>::from(-5), Amount::from(5));
+        assert_eq!(-AmountNoCopy::<Debt, i32>::from(0), AmountNoCopy::from(0));
+
+        assert_eq!(-Amount::<Celsius, f64>::from(1.5), Amount::from(-1.5));
+        assert_eq!(-Amount::<Celsius, f64>::from(-0.25), Amount::from(0.25));
+    }
+
+    #[test]
+    fn test_checked_u64() {
+        enum Cents {}
+        type Money = Amount<Cents, u64>;
+
+        let max = Money::from(u64::MAX);
+        assert_eq!(max.checked_add(Money::from(0)), Some(max));
+        assert_eq!(max.checked_add(Money::from(1)), None);
+        assert_eq!(Money::from(0).checked_sub(Money::from(1)), None);
+        assert_eq!(
+            Money::from(1).checked_sub(Money::from(1)),
+            Some(Money::from(0))
+        );
+        assert_eq!(max.checked_mul(1), Some(max));
+        assert_eq!(max.checked_mul(2), None);
+    }
+
+    #[test]
+    fn test_checked_i32() {
+        enum Balance {}
+        type Bal = AmountNoCopy<Balance, i32>;
+
+        assert_eq!(
+            Bal::from(i32::MAX - 1).checked_add(Bal::from(1)),
+            Some(Bal::from(i32::MAX))
+        );
+        assert_eq!(Bal::from(i32::MAX).checked_add(Bal::from(1)), None);
+        assert_eq!(
+            Bal::from(i32::MIN + 1).checked_sub(Bal::from(1)),
+            Some(Bal::from(i32::MIN))
+        );
+        assert_eq!(Bal::from(i32::MIN).checked_sub(Bal::from(1)), None);
+        assert_eq!(Bal::from(i32::MIN).checked_mul(-1), None);
+        assert_eq!(Bal::from(-3).checked_mul(-2), Some(Bal::from(6)));
+    }
+
+    #[test]
+    fn test_saturating() {
+        enum Apples {}
+        type NumApples = Amount<Apples, u64>;
+
+        assert_eq!(
+            NumApples::from(3).saturating_sub(NumApples::from(5)),
+            NumApples::from(0)
+        );
+        assert_eq!(
+            NumApples::from(5).saturating_sub(NumApples::from(3)),
+            NumApples::from(2)
+        );
+        assert_eq!(
+            NumApples::from(u64::MAX).saturating_add(NumApples::from(1)),
+            NumApples::from(u64::MAX)
+        );
+
+        enum Celsius {}
+        type Temp = Amount<Celsius, i8>;
+
+        assert_eq!(
+            Temp::from(i8::MAX).saturating_add(Temp::from(1)),
+            Temp::from(i8::MAX)
+        );
+        assert_eq!(
+            Temp::from(i8::MIN).saturating_add(Temp::from(-1)),
+            Temp::from(i8::MIN)
+        );
+        assert_eq!(
+            Temp::from(i8::MIN).saturating_sub(Temp::from(1)),
+            Temp::from(i8::MIN)
+        );
+        assert_eq!(
+            Temp::from(i8::MAX).saturating_sub(Temp::from(-1)),
+            Temp::from(i8::MAX)
+        );
+        assert_eq!(Temp::from(-3).saturating_sub(Temp::from(4)), Temp::from(-7));
+    }
+
+    #[test]
+    fn test_wrapping() {
+        enum Tick {}
+        type Ticks = Amount<Tick, u8>;
+
+        assert_eq!(
+            Ticks::from(255).wrapping_add(Ticks::from(1)),
+            Ticks::from(0)
+        );
+        assert_eq!(
+            Ticks::from(0).wrapping_sub(Ticks::from(1)),
+            Ticks::from(255)
+        );
+        assert_eq!(Ticks::from(128).wrapping_mul(2), Ticks::from(0));
+        assert_eq!(Ticks::from(3).wrapping_mul(2), Ticks::from(6));
+
+        type SignedTicks = Amount<Tick, i8>;
+        assert_eq!(
+            SignedTicks::from(i8::MAX).wrapping_add(SignedTicks::from(1)),
+            SignedTicks::from(i8::MIN)
+        );
+        assert_eq!(
+            SignedTicks::from(i8::MIN).wrapping_sub(SignedTicks::from(1)),
+            SignedTicks::from(i8::MAX)
+        );
+    }
+
+    #[test]
+    fn test_try_from_narrowing() {
+        enum Bytes {}
+
+        let small = Amount::<Bytes, u64>::from(u64::from(u32::MAX));
+        assert_eq!(
+            Amount::<Bytes, u32>::try_from(small),
+            Ok(Amount::from(u32::MAX))
+        );
+
+        let big = Amount::<Bytes, u64>::from(u64::from(u32::MAX) + 1);
+        assert!(Amount::<Bytes, u32>::try_from(big).is_err());
+
+        let negative = AmountNoCopy::<Bytes, i16>::from(-1);
+        assert!(AmountNoCopy::<Bytes, u8>::try_from(negative).is_err());
+        let narrowed: Result<AmountNoCopy<Bytes, i8>, _> =
+            AmountNoCopy::<Bytes, i16>::from(-128).try_into();
+        assert_eq!(narrowed, Ok(AmountNoCopy::from(-128)));
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_zero() {
+        use num_traits::Zero;
+
+        enum Joules {}
+        type Energy = Amount<Joules, f64>;
+
+        assert!(Energy::zero().is_zero());
+        assert!(!Energy::from(0.5).is_zero());
+        assert_eq!(Energy::zero() + Energy::from(0.5), Energy::from(0.5));
+        assert!(Amount::<Joules, u64>::zero().is_zero());
+    }
+
+    #[test]
+    fn test_bounds() {
+        enum Bytes {}
+
+        assert_eq!(Amount::<Bytes, u8>::MAX.get(), 255);
+        assert_eq!(Amount::<Bytes, u8>::MIN.get(), 0);
+        assert_eq!(Amount::<Bytes, i64>::MIN.get(), i64::MIN);
+        assert_eq!(
+            AmountNoCopyNoDefault::<Bytes, usize>::MAX.into_inner(),
+            usize::MAX
+        );
+        assert_eq!(Amount::<Bytes, u64>::MAX.checked_add(Amount::from(1)), None);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_bytemuck() {
+        enum Sample {}
+        type Samples = Amount<Sample, u32>;
+
+        let amounts = [Samples::from(1), Samples::from(2), Samples::from(u32::MAX)];
+        let reprs: &[u32] = bytemuck::cast_slice(&amounts);
+        assert_eq!(reprs, &[1, 2, u32::MAX]);
+
+        let back: &[Samples] = bytemuck::cast_slice(reprs);
+        assert_eq!(back, &amounts);
+
+        let zeroed: AmountNoDefault<Sample, i16> = bytemuck::Zeroable::zeroed();
+        assert_eq!(zeroed, AmountNoDefault::from(0));
+    }
+
+    #[test]
+    fn test_cast_slice() {
+        enum NodeId {}
+        type Nodes = Amount<NodeId, u64>;
+
+        let mut reprs = [1u64, 2, 3];
+        assert_eq!(
+            Nodes::cast_slice(&reprs),
+            &[Nodes::from(1), Nodes::from(2), Nodes::from(3)]
+        );
+
+        for node in Nodes::cast_slice_mut(&mut reprs) {
+            *node += Nodes::from(10);
+        }
+        assert_eq!(reprs, [11, 12, 13]);
+
+        let empty: &[u64] = &[];
+        assert!(AmountNoCopy::<NodeId, u64>::cast_slice(empty).is_empty());
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn test_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        enum Message {}
+
+        let bytes = [1u8, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut u = Unstructured::new(&bytes);
+        let amount = Amount::<Message, u64>::arbitrary(&mut u).unwrap();
+        let mut u = Unstructured::new(&bytes);
+        assert_eq!(amount.get(), u64::arbitrary(&mut u).unwrap());
+
+        let mut u = Unstructured::new(&bytes);
+        let id = Id::<Message, u32>::arbitrary(&mut u).unwrap();
+        let mut u = Unstructured::new(&bytes);
+        assert_eq!(*id.get(), u32::arbitrary(&mut u).unwrap());
+
+        let mut u = Unstructured::new(&bytes);
+        let instant = InstantNoCopy::<Message, i16>::arbitrary(&mut u).unwrap();
+        let mut u = Unstructured::new(&bytes);
+        assert_eq!(instant.into_inner(), i16::arbitrary(&mut u).unwrap());
+
+        assert_eq!(Amount::<Message, u64>::size_hint(0), u64::size_hint(0));
+    }
+
+    #[cfg(feature = "proptest")]
+    mod proptests {
+        extern crate std;
+        use crate::*;
+        use proptest::prelude::*;
+
+        enum Meters {}
+        type Distance = Amount<Meters, u64>;
+
+        proptest! {
+            #[test]
+            fn add_then_sub(a in amount_in_range(0u64..1 << 63), b in amount_in_range(0u64..1 << 63)) {
+                let (a, b): (Distance, Distance) = (a, b);
+                prop_assert_eq!((a + b) - b, a);
+            }
+
+            #[test]
+            fn arbitrary_wraps_repr(a: Amount<Meters, i32>) {
+                prop_assert_eq!(Amount::<Meters, i32>::from(a.get()), a);
+            }
+        }
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn test_quickcheck() {
+        extern crate std;
+        use core::sync::atomic::{AtomicI32, Ordering};
+        use quickcheck::{Arbitrary, QuickCheck, TestResult};
+        use std::vec::Vec;
+
+        enum Count {}
+        type Counter = Amount<Count, i32>;
+
+        let shrunk: Vec<i32> = Counter::from(100).shrink().map(|c| c.get()).collect();
+        assert_eq!(shrunk, 100i32.shrink().collect::<Vec<_>>());
+
+        // A failing property. QuickCheck shrinks the counterexample (while
+        // keeping the unit). The last failing input is the one it reports.
+        static LAST_FAILURE: AtomicI32 = AtomicI32::new(0);
+        fn below_100(c: Counter) -> bool {
+            let passes = c.get() < 100;
+            if !passes {
+                LAST_FAILURE.store(c.get(), Ordering::SeqCst);
+            }
+            passes
+        }
+        let failure: TestResult = QuickCheck::new()
+            .tests(10_000)
+            .quicktest(below_100 as fn(Counter) -> bool)
+            .unwrap_err();
+        assert!(failure.is_failure());
+        // Shrunk to the minimal counterexample.
+        assert_eq!(LAST_FAILURE.load(Ordering::SeqCst), 100);
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn test_borsh() {
+        enum Lamports {}
+
+        let amount = Amount::<Lamports, u64>::from(0x0102_0304_0506_0708);
+        let bytes = borsh::to_vec(&amount).unwrap();
+        assert_eq!(bytes, borsh::to_vec(&0x0102_0304_0506_0708u64).unwrap());
+        assert_eq!(
+            borsh::from_slice::<Amount<Lamports, u64>>(&bytes).unwrap(),
+            amount
+        );
+
+        let id = IdNoCopy::<Lamports, [u8; 4]>::from([1, 2, 3, 4]);
+        let bytes = borsh::to_vec(&id).unwrap();
+        assert_eq!(bytes, borsh::to_vec(&[1u8, 2, 3, 4]).unwrap());
+        assert_eq!(
+            borsh::from_slice::<IdNoCopy<Lamports, [u8; 4]>>(&bytes).unwrap(),
+            id
+        );
+
+        let instant = Instant::<Lamports, i32>::from(-7);
+        let bytes = borsh::to_vec(&instant).unwrap();
+        assert_eq!(bytes, borsh::to_vec(&-7i32).unwrap());
+        assert_eq!(
+            borsh::from_slice::<Instant<Lamports, i32>>(&bytes).unwrap(),
+            instant
+        );
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv() {
+        use rkyv::rancor::Error;
+
+        enum BlockHeight {}
+        type Height = Amount<BlockHeight, u64>;
+
+        let height = Height::from(840_000);
+        let bytes = rkyv::to_bytes::<Error>(&height).unwrap();
+        assert_eq!(
+            &bytes[..],
+            &rkyv::to_bytes::<Error>(&840_000u64).unwrap()[..]
+        );
+
+        let archived = rkyv::access::<rkyv::Archived<Height>, Error>(&bytes).unwrap();
+        // The archived amount keeps its unit.
+        let _: &Amount<BlockHeight, rkyv::Archived<u64>> = archived;
+        assert_eq!(archived.get_ref().to_native(), 840_000);
+
+        let deserialized = rkyv::deserialize::<Height, Error>(archived).unwrap();
+        assert_eq!(deserialized, height);
+    }
+
+    #[test]
+    fn test_clamp_min_max() {
+        enum Percent {}
+        type Percentage = AmountNoCopy<Percent, u8>;
+
+        let lo = || Percentage::from(10);
+        let hi = || Percentage::from(100);
+        assert_eq!(Percentage::from(5).clamp(lo(), hi()), lo());
+        assert_eq!(Percentage::from(200).clamp(lo(), hi()), hi());
+        assert_eq!(Percentage::from(50).clamp(lo(), hi()), Percentage::from(50));
+        assert_eq!(lo().min(hi()), lo());
+        assert_eq!(lo().max(hi()), hi());
+    }
+
+    #[test]
+    fn test_abs_diff() {
+        enum Error {}
+
+        let (a, b) = (
+            Amount::<Error, u32>::from(5),
+            Amount::<Error, u32>::from(12),
+        );
+        assert_eq!(a.abs_diff(b), Amount::from(7));
+        assert_eq!(b.abs_diff(a), Amount::from(7));
+        assert_eq!(a.abs_diff(a), Amount::from(0));
+
+        let (a, b) = (
+            Amount::<Error, i32>::from(-5),
+            Amount::<Error, i32>::from(12),
+        );
+        assert_eq!(a.abs_diff(b), Amount::<Error, u32>::from(17));
+        assert_eq!(b.abs_diff(a), Amount::<Error, u32>::from(17));
+        assert_eq!(
+            Amount::<Error, i32>::MIN.abs_diff(Amount::<Error, i32>::MAX),
+            Amount::<Error, u32>::MAX
+        );
+
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let expected = (i16::from(a) - i16::from(b)).unsigned_abs() as u8;
+                assert_eq!(
+                    Amount::<Error, i8>::from(a).abs_diff(Amount::from(b)),
+                    Amount::<Error, u8>::from(expected)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_midpoint() {
+        enum Index {}
+        type Offset = Amount<Index, u64>;
+
+        assert_eq!(
+            Offset::from(u64::MAX).midpoint(Offset::from(u64::MAX - 2)),
+            Offset::from(u64::MAX - 1)
+        );
+        assert_eq!(Offset::MAX.midpoint(Offset::MAX), Offset::MAX);
+        assert_eq!(Offset::from(1).midpoint(Offset::from(4)), Offset::from(2));
+        assert_eq!(
+            Amount::<Index, i32>::from(-3).midpoint(Amount::from(0)),
+            Amount::from(-1)
+        );
+
+        // Rounding down (unsigned), and towards zero (signed), at the bounds.
+        assert_eq!(
+            Offset::MAX.midpoint(Offset::from(0)),
+            Offset::from(u64::MAX / 2)
+        );
+        assert_eq!(Offset::from(0).midpoint(Offset::from(1)), Offset::from(0));
+        type Delta = Amount<Index, i32>;
+        assert_eq!(Delta::MIN.midpoint(Delta::MAX), Delta::from(0));
+        assert_eq!(Delta::MAX.midpoint(Delta::MIN), Delta::from(0));
+        assert_eq!(Delta::MIN.midpoint(Delta::MIN), Delta::MIN);
+        assert_eq!(Delta::MAX.midpoint(Delta::MAX), Delta::MAX);
+        assert_eq!(
+            Delta::MIN.midpoint(Delta::from(i32::MIN + 1)),
+            Delta::from(i32::MIN + 1)
+        );
+        assert_eq!(
+            Delta::MAX.midpoint(Delta::from(i32::MAX - 1)),
+            Delta::from(i32::MAX - 1)
+        );
+        assert_eq!(Delta::from(-1).midpoint(Delta::from(0)), Delta::from(0));
+
+        // `/` on the wider type rounds towards zero, too.
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let expected = ((i16::from(a) + i16::from(b)) / 2) as i8;
+                assert_eq!(
+                    Amount::<Index, i8>::from(a).midpoint(Amount::from(b)),
+                    Amount::from(expected)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_range() {
+        enum Row {}
+        type RowIndex = AmountNoCopy<Row, i16>;
+
+        let mut rows = RowIndex::range(RowIndex::from(-2), RowIndex::from(3));
+        assert_eq!(rows.next(), Some(RowIndex::from(-2)));
+        assert_eq!(rows.by_ref().count(), 4);
+        assert_eq!(rows.next(), None);
+
+        assert_eq!(
+            RowIndex::range(RowIndex::from(5), RowIndex::from(5)).count(),
+            0
+        );
+        assert_eq!(
+            RowIndex::range(RowIndex::from(5), RowIndex::from(1)).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_with_flags() {
+        enum Apples {}
+
+        let a = AmountNoCopyNoDefault::<Apples, u32>::from(3);
+        let b: AmountNoCopy<Apples, u32> = a.with_flags();
+        let c: AmountNoDefault<Apples, u32> = b.with_flags();
+        let d: Amount<Apples, u32> = c.with_flags();
+        let e: AmountNoCopyNoDefault<Apples, u32> = d.with_flags();
+        assert_eq!(e, AmountNoCopyNoDefault::from(3));
+
+        let copy: Amount<Apples, u32> = AmountNoCopy::<Apples, u32>::from(4).into_copy();
+        let no_copy: AmountNoCopy<Apples, u32> = copy.into_no_copy();
+        assert_eq!(no_copy, AmountNoCopy::from(4));
+
+        let copy: AmountNoDefault<Apples, u32> =
+            AmountNoCopyNoDefault::<Apples, u32>::from(5).into_copy();
+        let no_copy: AmountNoCopyNoDefault<Apples, u32> = copy.into_no_copy();
+        assert_eq!(no_copy, AmountNoCopyNoDefault::from(5));
+    }
+
+    #[test]
+    fn test_debug_with_unit() {
+        extern crate std;
+        use std::format;
+
+        struct Cents;
+        let price = AmountNoCopy::<Cents, i32>::from(-5);
+        let debug = format!("{:?}", price.debug_with_unit());
+        assert!(debug.contains("Cents"));
+        assert_eq!(debug, "Amount<Cents>(-5)");
+    }
+
+    #[test]
+    fn test_const_get() {
+        enum Meters {}
+        type Distance = Amount<Meters, u64>;
+
+        const ASTRONOMICAL_UNIT: Distance = Distance::new(149_597_870_700);
+        const HALF_AU: Distance = Distance::new(*ASTRONOMICAL_UNIT.get_ref() / 2);
+        const HALF_AU_METERS: u64 = *HALF_AU.get_ref();
+        assert_eq!(HALF_AU_METERS, 74_798_935_350);
+        assert_eq!(HALF_AU, Distance::from(74_798_935_350));
+    }
+
+    #[test]
+    fn test_const_ops() {
+        enum Cents {}
+        type Money = AmountNoCopy<Cents, i32>;
+
+        const A: Money = Money::new(-300);
+        const B: Money = Money::new(1_000);
+        const SUM: Money = A.add_const(B);
+        const TRIPLE: Money = Money::new(7).mul_const(3);
+        assert_eq!(SUM, Money::from(700));
+        assert_eq!(TRIPLE, Money::from(21));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_to_grouped_string() {
+        enum Cents {}
+
+        let grouped = |n: i64| Amount::<Cents, i64>::from(n).to_grouped_string(',');
+        assert_eq!(grouped(0), "0");
+        assert_eq!(grouped(999), "999");
+        assert_eq!(grouped(1_000), "1,000");
+        assert_eq!(grouped(12_345), "12,345");
+        assert_eq!(grouped(123_456), "123,456");
+        assert_eq!(grouped(1_234_567), "1,234,567");
+        assert_eq!(grouped(-1), "-1");
+        assert_eq!(grouped(-100_000), "-100,000");
+        assert_eq!(grouped(i64::MIN), "-9,223,372,036,854,775,808");
+        assert_eq!(
+            Amount::<Cents, u128>::MAX.to_grouped_string(' '),
+            "340 282 366 920 938 463 463 374 607 431 768 211 455"
+        );
+    }
+
+    #[test]
+    fn test_add_get_entry() {
+        extern crate std;
+        use std::collections::HashMap;
+
+        enum Bytes {}
+        type Size = Amount<Bytes, u8>;
+
+        let mut totals: HashMap<&str, Size> = HashMap::new();
+        assert_eq!(totals.entry("a").or_default().add_get(Size::from(100)), 100);
+        assert_eq!(totals.entry("a").or_default().add_get(Size::from(50)), 150);
+        assert_eq!(totals.entry("b").or_default().add_get(Size::from(1)), 1);
+
+        assert!(totals
+            .entry("a")
+            .or_default()
+            .checked_add_assign(Size::from(105)));
+        assert!(!totals
+            .entry("a")
+            .or_default()
+            .checked_add_assign(Size::from(1)));
+        assert_eq!(totals["a"], 255);
+        assert_eq!(totals["b"], 1);
+    }
+
+    #[test]
+    fn test_from_widening() {
+        enum Bytes {}
+
+        let wide: Amount<Bytes, u64> = Amount::<Bytes, u8>::from(200).into();
+        assert_eq!(wide, 200);
+        let wide = Amount::<Bytes, i64>::from(AmountNoCopy::<Bytes, i16>::from(-300).with_flags());
+        assert_eq!(wide, -300);
+        let wide: Amount<Bytes, i64> = Amount::<Bytes, i16>::MIN.into();
+        assert_eq!(wide, i64::from(i16::MIN));
+    }
+
+    #[test]
+    fn test_is_in_range() {
+        use core::ops::Bound;
+
+        enum Percent {}
+        type Percentage = Amount<Percent, u8>;
+        let p = Percentage::from;
+
+        // Half-open:
+        assert!(p(10).is_in_range(p(10)..p(20)));
+        assert!(!p(20).is_in_range(p(10)..p(20)));
+        assert!(p(200).is_in_range(p(10)..));
+        assert!(!p(20).is_in_range(..p(20)));
+        // Closed:
+        assert!(p(20).is_in_range(p(10)..=p(20)));
+        assert!(!p(9).is_in_range(p(10)..=p(20)));
+        assert!(p(0).is_in_range(..=p(0)));
+        // Unbounded:
+        assert!(p(255).is_in_range(..));
+        // Exclusive start, via `Bound`:
+        assert!(!p(10).is_in_range((Bound::Excluded(p(10)), Bound::Unbounded)));
+        assert!(p(11).is_in_range((Bound::Excluded(p(10)), Bound::Included(p(11)))));
+    }
+
+    #[test]
+    fn test_mul_as() {
+        enum Meters {}
+        enum SquareMeters {}
+        enum Watts {}
+        enum Hours {}
+        enum WattHours {}
+
+        let (width, length) = (
+            Amount::<Meters, u32>::from(3),
+            Amount::<Meters, u32>::from(5),
+        );
+        let area: Amount<SquareMeters, u32> = width.mul_as(length);
+        assert_eq!(area, 15);
+
+        let energy = AmountNoCopy::<Watts, i64>::from(60)
+            .mul_as::<WattHours, _>(AmountNoCopy::<Hours, i64>::from(3));
+        assert_eq!(energy, AmountNoCopy::<WattHours, i64>::from(180));
+    }
+
+    #[test]
+    fn test_countdown() {
+        enum Retry {}
+        type Retries = crate::Amount<Retry, u32>;
+
+        let left = Retries::from(4).countdown().map(|r| r.get());
+        assert!(left.eq([3, 2, 1, 0].iter().copied()));
+        assert_eq!(Retries::from(0).countdown().count(), 0);
+        assert_eq!(crate::Amount::<Retry, i8>::from(-3).countdown().count(), 0);
+    }
+
+    #[test]
+    fn test_div_floor_ceil_rem() {
+        enum Seconds {}
+        type Span = Amount<Seconds, u64>;
+
+        assert_eq!(Span::from(20).div_floor(Span::from(5)), 4);
+        assert_eq!(Span::from(20).div_ceil(Span::from(5)), 4);
+        assert_eq!(Span::from(20).div_rem(Span::from(5)), (4, Span::from(0)));
+
+        assert_eq!(Span::from(21).div_floor(Span::from(5)), 4);
+        assert_eq!(Span::from(21).div_ceil(Span::from(5)), 5);
+        assert_eq!(Span::from(21).div_rem(Span::from(5)), (4, Span::from(1)));
+        assert_eq!(Span::MAX.div_ceil(Span::from(2)), u64::MAX / 2 + 1);
+        assert_eq!(Span::MAX.div_ceil(Span::MAX), 1);
+        assert_eq!(Span::from(0).div_ceil(Span::from(5)), 0);
+
+        type Delta = Amount<Seconds, i32>;
+        assert_eq!(Delta::from(-21).div_floor(Delta::from(5)), -5);
+        assert_eq!(Delta::from(-21).div_ceil(Delta::from(5)), -4);
+        assert_eq!(
+            Delta::from(-21).div_rem(Delta::from(5)),
+            (-5, Delta::from(4))
+        );
+        assert_eq!(
+            Delta::from(21).div_rem(Delta::from(-5)),
+            (-5, Delta::from(-4))
+        );
+        assert_eq!(
+            Delta::from(-20).div_rem(Delta::from(5)),
+            (-4, Delta::from(0))
+        );
+        assert_eq!(Delta::from(-21).div_ceil(Delta::from(-5)), 5);
+    }
+
+    #[test]
+    fn test_gcd_lcm() {
+        enum Ticks {}
+        type Period = Amount<Ticks, u64>;
+
+        // Coprime.
+        assert_eq!(Period::from(9).gcd(Period::from(28)), Period::from(1));
+        assert_eq!(Period::from(9).lcm(Period::from(28)), Period::from(252));
+        // Overlapping.
+        assert_eq!(Period::from(24).gcd(Period::from(36)), Period::from(12));
+        assert_eq!(Period::from(24).lcm(Period::from(36)), Period::from(72));
+        assert_eq!(Period::from(8).gcd(Period::from(32)), Period::from(8));
+        assert_eq!(Period::from(32).lcm(Period::from(8)), Period::from(32));
+        // Zero.
+        assert_eq!(Period::from(7).gcd(Period::from(0)), Period::from(7));
+        assert_eq!(Period::from(0).gcd(Period::from(0)), Period::from(0));
+        assert_eq!(Period::from(7).lcm(Period::from(0)), Period::from(0));
+        // Dividing first: the naive product, 2^63 * 2^63, would overflow.
+        let half = Period::from(1 << 63);
+        assert_eq!(half.lcm(half), half);
+        assert_eq!(
+            Amount::<Ticks, u8>::from(120).lcm(Amount::from(40)),
+            Amount::from(120)
+        );
+
+        // Signed: of the absolute values.
+        type Offset = Amount<Ticks, i32>;
+        assert_eq!(Offset::from(-24).gcd(Offset::from(36)), Offset::from(12));
+        assert_eq!(Offset::from(24).gcd(Offset::from(-36)), Offset::from(12));
+        assert_eq!(Offset::from(-24).lcm(Offset::from(-36)), Offset::from(72));
+        assert_eq!(Offset::from(-7).gcd(Offset::from(0)), Offset::from(7));
+        assert_eq!(Offset::from(-7).lcm(Offset::from(0)), Offset::from(0));
+        assert_eq!(Offset::MIN.gcd(Offset::from(6)), Offset::from(2));
+        assert_eq!(
+            Offset::from(i32::MIN / 2).lcm(Offset::from(-4)),
+            Offset::from(1 << 30)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic)]
+    fn test_gcd_min_overflow() {
+        enum Ticks {}
+        type Offset = Amount<Ticks, i8>;
+
+        // 128 doesn't fit `i8`.
+        assert_eq!(Offset::MIN.gcd(Offset::from(0)), Offset::MIN);
+    }
+
+    #[test]
+    fn test_same_value() {
+        enum Apples {}
+
+        let copy = Amount::<Apples, u64>::from(7);
+        assert!(copy.same_value(&AmountNoCopy::from(7)));
+        assert!(copy.same_value(&AmountNoDefault::from(7)));
+        assert!(AmountNoCopyNoDefault::from(7).same_value(&copy));
+        assert!(!copy.same_value(&AmountNoCopyNoDefault::from(8)));
+    }
+
+    #[test]
+    fn test_shl_shr() {
+        enum Fixed {}
+        type Q16 = Amount<Fixed, i64>;
+
+        let one: Q16 = Q16::from(1) << 16;
+        assert_eq!(one, Q16::from(65_536));
+        assert_eq!(one >> 16, Q16::from(1));
+        assert_eq!(Q16::from(-8) >> 1, Q16::from(-4));
+
+        let mut x = Q16::from(3);
+        x <<= 4;
+        assert_eq!(x, Q16::from(48));
+        x >>= 5;
+        assert_eq!(x, Q16::from(1));
+
+        // Bits shifted out are lost, like for the primitive.
+        assert_eq!(
+            Amount::<Fixed, u8>::from(0b1000_0001) << 1,
+            Amount::from(0b10)
+        );
+    }
+
+    #[test]
+    fn test_signum() {
+        enum Balance {}
+        type Money = Amount<Balance, i64>;
+
+        assert_eq!(Money::from(-7).signum(), Money::from(-1));
+        assert!(Money::from(-7).is_negative());
+        assert!(!Money::from(-7).is_positive());
+
+        assert_eq!(Money::from(0).signum(), Money::from(0));
+        assert!(!Money::from(0).is_negative());
+        assert!(!Money::from(0).is_positive());
+
+        assert_eq!(Money::from(42).signum(), Money::from(1));
+        assert!(!Money::from(42).is_negative());
+        assert!(Money::from(42).is_positive());
+    }
+
+    #[test]
+    fn test_abs() {
+        enum Temperature {}
+        type Celsius = Amount<Temperature, i32>;
+
+        assert_eq!(Celsius::from(-5).abs(), Celsius::from(5));
+        assert_eq!(Celsius::from(0).abs(), Celsius::from(0));
+        assert_eq!(Celsius::from(7).abs(), Celsius::from(7));
+        assert_eq!(Celsius::from(i32::MIN + 1).abs(), Celsius::from(i32::MAX));
+
+        type Kelvin = Amount<Temperature, f64>;
+        assert_eq!(Kelvin::from(-1.25).abs(), Kelvin::from(1.25));
+        assert!(Kelvin::from(-0.0).abs().get().is_sign_positive());
+        assert!(Kelvin::from(f64::NAN).abs().get().is_nan());
+        assert_eq!(
+            Amount::<Temperature, f32>::from(f32::NEG_INFINITY).abs(),
+            Amount::from(f32::INFINITY)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic)]
+    fn test_abs_min() {
+        enum Temperature {}
+        let min = Amount::<Temperature, i32>::from(i32::MIN);
+        // Only reached in release builds, where the overflow wraps.
+        assert_eq!(min.abs(), min);
+    }
+
+    #[test]
+    fn test_min_max_by_repr() {
+        extern crate std;
+        use std::vec;
+
+        enum Millis {}
+        type Latency = Amount<Millis, u32>;
+
+        let empty: [Latency; 0] = [];
+        assert_eq!(min_by_repr(empty.iter().copied()), None);
+        assert_eq!(max_by_repr(empty.iter().copied()), None);
+
+        let one = [Latency::from(5)];
+        assert_eq!(min_by_repr(one.iter().copied()), Some(Latency::from(5)));
+        assert_eq!(max_by_repr(one.iter().copied()), Some(Latency::from(5)));
+
+        let many: &[Latency] = &[7.into(), 2.into(), 9.into(), 2.into(), 9.into()];
+        assert_eq!(min_by_repr(many.iter().copied()), Some(Latency::from(2)));
+        assert_eq!(max_by_repr(many.iter().copied()), Some(Latency::from(9)));
+
+        // Non-`Copy` amounts are moved in (and out).
+        let ids = vec![
+            AmountNoCopy::<Millis, u32>::from(3),
+            AmountNoCopy::<Millis, u32>::from(1),
+        ];
+        assert_eq!(min_by_repr(ids).map(|a| a.get()), Some(1));
+    }
+
+    #[test]
+    fn test_explicit_default() {
+        enum Cents {}
+
+        assert_eq!(
+            AmountNoDefault::<Cents, u64>::explicit_default(),
+            AmountNoDefault::from(0)
+        );
+        assert_eq!(
+            AmountNoCopyNoDefault::<Cents, i8>::explicit_default().get(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_add_sub_repr() {
+        enum Bytes {}
+        type Size = Amount<Bytes, u64>;
+
+        let mut size = Size::from(10);
+        size += 5;
+        size += Size::from(5);
+        assert_eq!(size, Size::from(20));
+        size -= 3;
+        size -= Size::from(7);
+        assert_eq!(size, Size::from(10));
+
+        assert_eq!(size + 1, Size::from(11));
+        assert_eq!(size - 1, Size::from(9));
+        assert_eq!(size + Size::from(2) - 2, size);
+
+        let mut no_copy = AmountNoCopy::<Bytes, u64>::from(1);
+        no_copy += 1;
+        assert_eq!(no_copy, AmountNoCopy::from(2));
+    }
+
+    #[test]
+    fn test_usize() {
+        enum Row {}
+
+        assert_eq!(Amount::<Row, u64>::from(7).try_into_usize(), Ok(7));
+        assert_eq!(Amount::<Row, u64>::from_usize(7), Amount::from(7));
+        assert_eq!(Amount::<Row, u8>::from_usize(255), Amount::from(255));
+
+        // Simulates a 32-bit target (where `u64` may not fit) by using a `Repr` that's wider than
+        // `usize` on any target.
+        let within = Amount::<Row, u128>::from(usize::MAX as u128);
+        assert_eq!(within.try_into_usize(), Ok(usize::MAX));
+        let beyond = Amount::<Row, u128>::from(usize::MAX as u128 + 1);
+        assert!(beyond.try_into_usize().is_err());
+
+        assert!(Amount::<Row, i8>::from(-1).try_into_usize().is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_usize_out_of_range() {
+        enum Row {}
+        let _ = Amount::<Row, u8>::from_usize(256);
+    }
+
+    #[test]
+    fn test_const_cmp() {
+        enum Celsius {}
+        type Temperature = Amount<Celsius, i16>;
+
+        const MIN: Temperature = Temperature::new(-40);
+        const MAX: Temperature = Temperature::new(85);
+        // `assert!` in a `const` needs Rust 1.57. These fail to compile (mismatched array length)
+        // unless the condition holds.
+        const _: [(); 1] = [(); MIN.const_lt(&MAX) as usize];
+        const _: [(); 1] = [(); !MAX.const_lt(&MIN) as usize];
+        const _: [(); 1] = [(); !MIN.const_lt(&MIN) as usize];
+        const _: [(); 1] = [(); MIN.const_eq(&Temperature::new(-40)) as usize];
+        const _: [(); 1] = [(); !MIN.const_eq(&MAX) as usize];
+
+        let no_copy = AmountNoCopy::<Celsius, u8>::new(1);
+        assert!(no_copy.const_lt(&AmountNoCopy::new(2)));
+    }
+
+    #[test]
+    fn test_scale_by() {
+        enum Cents {}
+        type Money = Amount<Cents, u64>;
+
+        assert_eq!(Money::from(200).scale_by(3, 4), Money::from(150));
+        assert_eq!(Money::from(10).scale_by(1, 3), Money::from(3));
+
+        // `u64::MAX * 3` overflows `u64`, but the widened product doesn't.
+        let max = Money::from(u64::MAX);
+        assert_eq!(max.scale_by(3, 4), Money::from(u64::MAX / 4 * 3 + 2));
+        assert_eq!(max.scale_by(u64::MAX, u64::MAX), max);
+
+        type Delta = Amount<Cents, i32>;
+        assert_eq!(Delta::from(-10).scale_by(1, 3), Delta::from(-3));
+        assert_eq!(
+            Delta::from(i32::MIN).scale_by(i32::MAX, i32::MAX),
+            Delta::from(i32::MIN)
+        );
+        assert_eq!(
+            Amount::<Cents, usize>::from(usize::MAX)
+                .scale_by(2, 2)
+                .get(),
+            usize::MAX
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scale_by_overflow() {
+        enum Cents {}
+        let _ = Amount::<Cents, u8>::from(200).scale_by(2, 1);
+    }
+
+    #[test]
+    fn test_checked_div_rem() {
+        enum Bytes {}
+        type Size = Amount<Bytes, u32>;
+
+        assert_eq!(Size::from(12).checked_div(Size::from(4)), Some(3));
+        assert_eq!(Size::from(13).checked_rem(Size::from(4)), Some(1));
+        assert_eq!(Size::from(12).checked_div(Size::from(0)), None);
+        assert_eq!(Size::from(12).checked_rem(Size::from(0)), None);
+
+        type Delta = Amount<Bytes, i8>;
+        assert_eq!(Delta::from(-7).checked_div(Delta::from(2)), Some(-3));
+        assert_eq!(Delta::from(i8::MIN).checked_div(Delta::from(-1)), None);
+    }
+
+    #[test]
+    fn test_eq_across_flags() {
+        enum Apples {}
+
+        macro_rules! assert_eq_across {
+            ($($lhs:ident)*; $rhs:tt) => {$(
+                assert_eq_across!(@row $lhs $rhs);
+            )*};
+            (@row $lhs:ident [$($rhs:ident)*]) => {$(
+                assert!($lhs::<Apples, u64>::new(7) == $rhs::<Apples, u64>::new(7));
+                assert!($lhs::<Apples, u64>::new(7) != $rhs::<Apples, u64>::new(8));
+            )*};
+        }
+
+        assert_eq_across!(
+            Amount AmountNoCopy AmountNoDefault AmountNoCopyNoDefault;
+            [Amount AmountNoCopy AmountNoDefault AmountNoCopyNoDefault]
+        );
     }
 }
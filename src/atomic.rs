@@ -0,0 +1,162 @@
+// Copyright 2024 Peter Lyons Kehl
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ops::AtomicRepr;
+use crate::Amount;
+use core::fmt;
+use core::marker::PhantomData;
+use core::sync::atomic::Ordering;
+
+/// An [Amount] that can be shared between threads, like
+/// [AtomicU64](core::sync::atomic::AtomicU64) and friends, but keeping the unit. It's
+/// `#[repr(transparent)]` over the atomic primitive of `Repr`. Only on 64-bit targets (see
+/// [AtomicRepr]).
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use core::sync::atomic::Ordering;
+/// use phantom_newtype::{Amount, AtomicAmount};
+///
+/// enum Requests {}
+///
+/// let counter = AtomicAmount::<Requests, u64>::new(Amount::from(5));
+/// assert_eq!(counter.fetch_add(Amount::from(2), Ordering::Relaxed), Amount::from(5));
+/// assert_eq!(counter.load(Ordering::Relaxed), Amount::from(7));
+/// ```
+#[repr(transparent)]
+pub struct AtomicAmount<Unit, Repr: AtomicRepr>(
+    Repr::Atomic,
+    PhantomData<core::sync::atomic::AtomicPtr<Unit>>,
+);
+
+impl<Unit, Repr: AtomicRepr> AtomicAmount<Unit, Repr> {
+    /// Creates an atomic amount with the given initial value.
+    pub fn new(amount: Amount<Unit, Repr>) -> Self {
+        Self(Repr::new_atomic(amount.get()), PhantomData)
+    }
+
+    /// Like `load` of the atomic primitives.
+    pub fn load(&self, order: Ordering) -> Amount<Unit, Repr> {
+        Amount::new(Repr::load(&self.0, order))
+    }
+
+    /// Like `store` of the atomic primitives.
+    pub fn store(&self, amount: Amount<Unit, Repr>, order: Ordering) {
+        Repr::store(&self.0, amount.get(), order)
+    }
+
+    /// Like `fetch_add` of the atomic primitives: It wraps around on
+    /// overflow, and it returns the previous amount.
+    pub fn fetch_add(&self, amount: Amount<Unit, Repr>, order: Ordering) -> Amount<Unit, Repr> {
+        Amount::new(Repr::fetch_add(&self.0, amount.get(), order))
+    }
+
+    /// Like `compare_exchange` of the atomic primitives.
+    pub fn compare_exchange(
+        &self,
+        current: Amount<Unit, Repr>,
+        new: Amount<Unit, Repr>,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Amount<Unit, Repr>, Amount<Unit, Repr>> {
+        Repr::compare_exchange(&self.0, current.get(), new.get(), success, failure)
+            .map(Amount::new)
+            .map_err(Amount::new)
+    }
+
+    /// Consumes the atomic and returns the contained amount. Like
+    /// `into_inner` of the atomic primitives, this is safe, because
+    /// passing `self` by value guarantees no other threads access it.
+    pub fn into_inner(self) -> Amount<Unit, Repr> {
+        Amount::new(Repr::into_inner(self.0))
+    }
+}
+
+impl<Unit, Repr: AtomicRepr + Default> Default for AtomicAmount<Unit, Repr> {
+    fn default() -> Self {
+        Self::new(Amount::default())
+    }
+}
+
+impl<Unit, Repr: AtomicRepr> From<Amount<Unit, Repr>> for AtomicAmount<Unit, Repr> {
+    fn from(amount: Amount<Unit, Repr>) -> Self {
+        Self::new(amount)
+    }
+}
+
+impl<Unit, Repr: AtomicRepr + fmt::Debug> fmt::Debug for AtomicAmount<Unit, Repr> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use crate::*;
+    use core::sync::atomic::Ordering;
+    use std::sync::Arc;
+    use std::vec::Vec;
+
+    enum Bytes {}
+
+    #[test]
+    fn test_threads() {
+        let total = Arc::new(AtomicAmount::<Bytes, u64>::default());
+        let threads: Vec<_> = (0..8)
+            .map(|_| {
+                let total = Arc::clone(&total);
+                std::thread::spawn(move || {
+                    for _ in 0..1_000 {
+                        total.fetch_add(Amount::from(3), Ordering::Relaxed);
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        let total = Arc::try_unwrap(total).unwrap();
+        assert_eq!(total.into_inner(), Amount::from(24_000));
+    }
+
+    #[test]
+    fn test_store_compare_exchange() {
+        let value = AtomicAmount::<Bytes, i32>::from(Amount::from(-1));
+        value.store(Amount::from(10), Ordering::SeqCst);
+        assert_eq!(
+            value.compare_exchange(
+                Amount::from(9),
+                Amount::from(0),
+                Ordering::SeqCst,
+                Ordering::SeqCst
+            ),
+            Err(Amount::from(10))
+        );
+        assert_eq!(
+            value.compare_exchange(
+                Amount::from(10),
+                Amount::from(0),
+                Ordering::SeqCst,
+                Ordering::SeqCst
+            ),
+            Ok(Amount::from(10))
+        );
+        assert_eq!(value.load(Ordering::SeqCst), Amount::from(0));
+    }
+}
@@ -12,6 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::amount::Amount;
+use crate::id::Id;
+use crate::instant::Instant;
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+use crate::trait_flag::TraitFlags;
 use core::fmt;
 use core::marker::PhantomData;
 
@@ -54,6 +59,118 @@ where
     Displayer: DisplayerOf<T>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        Displayer::display(&self.value, f)
+        Displayer::display(self.value, f)
     }
 }
+
+/// Like [DisplayerOf], but with a context (like a locale) passed to `display_with()` of
+/// [Amount], [Id] or [Instant].
+///
+/// [Amount]: crate::Amount
+/// [Id]: crate::Id
+/// [Instant]: crate::Instant
+pub trait DisplayerWith<T, Ctx> {
+    /// Formats `value` into `f`, as appropriate for `ctx`.
+    fn display_with(value: &T, ctx: &Ctx, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// An object `DisplayWithProxy`, when is asked to display itself,
+/// displays `T` with a context `Ctx` using the specified `Displayer`.
+pub struct DisplayWithProxy<'a, T, Ctx, Displayer>
+where
+    Displayer: DisplayerWith<T, Ctx>,
+{
+    value: &'a T,
+    ctx: Ctx,
+    displayer_tag: PhantomData<Displayer>,
+}
+
+impl<'a, T, Ctx, Displayer> DisplayWithProxy<'a, T, Ctx, Displayer>
+where
+    Displayer: DisplayerWith<T, Ctx>,
+{
+    pub fn new(value: &'a T, ctx: Ctx) -> Self {
+        Self {
+            value,
+            ctx,
+            displayer_tag: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, Ctx, Displayer> fmt::Display for DisplayWithProxy<'a, T, Ctx, Displayer>
+where
+    Displayer: DisplayerWith<T, Ctx>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Displayer::display_with(self.value, &self.ctx, f)
+    }
+}
+
+/// Implement this (empty) marker trait for a unit (or entity) type to make `display()` of
+/// [Amount], [Id] and [Instant] of that unit use [fmt::Display] of `Repr`, without implementing
+/// [DisplayerOf] by hand.
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::{Amount, UseReprDisplay};
+///
+/// struct Cents;
+/// impl UseReprDisplay for Cents {}
+///
+/// assert_eq!(format!("{}", Amount::<Cents, u64>::from(1005).display()), "1005");
+/// assert_eq!(format!("{:>6}", Amount::<Cents, u64>::from(7).display()), "     7");
+/// ```
+///
+/// [Amount]: crate::Amount
+/// [Id]: crate::Id
+/// [Instant]: crate::Instant
+pub trait UseReprDisplay {}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> DisplayerOf<Amount<TF, Unit, Repr>> for Unit
+where
+    Unit: UseReprDisplay,
+    Repr: fmt::Display,
+{
+    fn display(amount: &Amount<TF, Unit, Repr>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(amount.get_ref(), f)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity, Repr> DisplayerOf<Id<TF, Entity, Repr>> for Entity
+where
+    Entity: UseReprDisplay,
+    Repr: fmt::Display,
+{
+    fn display(id: &Id<TF, Entity, Repr>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(id.get_ref(), f)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> DisplayerOf<Instant<TF, Unit, Repr>> for Unit
+where
+    Unit: UseReprDisplay,
+    Repr: fmt::Display,
+{
+    fn display(instant: &Instant<TF, Unit, Repr>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(instant.get_ref(), f)
+    }
+}
+
+/// Implement for a unit (marker) type to display amounts of it with a
+/// suffix, like `5 m`, through `display_labeled()` of [Amount]. Unlike
+/// [DisplayerOf], this doesn't customize how the `Repr` is formatted.
+/// ([fmt::Display] of [Amount] itself stays bare.)
+///
+/// [Amount]: crate::Amount
+pub trait UnitLabel {
+    /// The suffix, separated from the `Repr` by a space.
+    const LABEL: &'static str;
+}
@@ -0,0 +1,126 @@
+// Copyright 2024 Peter Lyons Kehl
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::amount::Amount;
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+use crate::trait_flag::TraitFlags;
+use core::convert::TryFrom;
+use core::time::Duration;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Implement for a unit (marker) type to convert between [Duration] and
+/// amounts of that unit (with `u128` as `Repr`).
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use core::time::Duration;
+/// use phantom_newtype::{Amount, DurationUnit};
+///
+/// enum Millis {}
+/// impl DurationUnit for Millis {
+///     const NANOS_PER_UNIT: u128 = 1_000_000;
+/// }
+/// type Timeout = Amount<Millis, u128>;
+///
+/// let timeout = Timeout::from_duration(Duration::from_secs(2));
+/// assert_eq!(timeout, Timeout::from(2_000));
+/// assert_eq!(timeout.as_duration(), Duration::from_millis(2_000));
+/// ```
+pub trait DurationUnit {
+    /// Length of one unit in nanoseconds. It must not be zero.
+    const NANOS_PER_UNIT: u128;
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit: DurationUnit> Amount<TF, Unit, u128> {
+    /// Converts a [Duration] to a whole number of units, truncating any
+    /// remainder (like [Duration::as_millis] does).
+    pub fn from_duration(duration: Duration) -> Self {
+        Self::new(duration.as_nanos() / Unit::NANOS_PER_UNIT)
+    }
+
+    /// Converts to a [Duration].
+    ///
+    /// # Panics
+    ///
+    /// If the result overflows [Duration] (or `u128` nanoseconds).
+    pub fn as_duration(&self) -> Duration {
+        let nanos = self
+            .get_ref()
+            .checked_mul(Unit::NANOS_PER_UNIT)
+            .expect("overflow when converting an amount to nanoseconds");
+        let secs = u64::try_from(nanos / NANOS_PER_SEC)
+            .expect("overflow when converting an amount to Duration");
+        Duration::new(secs, (nanos % NANOS_PER_SEC) as u32)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit: DurationUnit> From<Duration> for Amount<TF, Unit, u128> {
+    fn from(duration: Duration) -> Self {
+        Self::from_duration(duration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use core::time::Duration;
+
+    enum Seconds {}
+    impl DurationUnit for Seconds {
+        const NANOS_PER_UNIT: u128 = 1_000_000_000;
+    }
+
+    enum Millis {}
+    impl DurationUnit for Millis {
+        const NANOS_PER_UNIT: u128 = 1_000_000;
+    }
+
+    #[test]
+    fn test_seconds() {
+        type Secs = Amount<Seconds, u128>;
+
+        assert_eq!(
+            Secs::from_duration(Duration::from_millis(2_999)),
+            Secs::from(2)
+        );
+        assert_eq!(Secs::from(Duration::from_secs(7)), Secs::from(7));
+        assert_eq!(Secs::from(7).as_duration(), Duration::from_secs(7));
+        assert_eq!(
+            Secs::from(u128::from(u64::MAX)).as_duration(),
+            Duration::from_secs(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_millis() {
+        type Ms = Amount<Millis, u128>;
+
+        assert_eq!(Ms::from_duration(Duration::from_micros(1_500)), Ms::from(1));
+        assert_eq!(Ms::from_duration(Duration::from_secs(3)), Ms::from(3_000));
+        assert_eq!(Ms::from(1_234).as_duration(), Duration::from_millis(1_234));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_overflow() {
+        let _ = Amount::<Seconds, u128>::from(u128::from(u64::MAX) + 1).as_duration();
+    }
+}
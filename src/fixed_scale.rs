@@ -0,0 +1,169 @@
+// Copyright 2024 Peter Lyons Kehl
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::amount::Amount;
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+use crate::trait_flag::TraitFlags;
+use core::convert::TryFrom;
+
+/// Implement for a unit (marker) type to use amounts of that unit (with `i64` as `Repr`) as binary
+/// fixed point numbers: The `Repr` is the value multiplied by `2^FRACTIONAL_BITS`.
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::{Amount, FixedScale};
+///
+/// enum FixedQ16 {}
+/// impl FixedScale for FixedQ16 {
+///     const FRACTIONAL_BITS: u32 = 16;
+/// }
+/// type Q16 = Amount<FixedQ16, i64>;
+///
+/// let half = Q16::from_f64(0.5);
+/// assert_eq!(half, Q16::from(32_768));
+/// assert_eq!(half.mul_fixed(Q16::from_f64(3.0)).to_f64(), 1.5);
+/// ```
+pub trait FixedScale {
+    /// Number of fractional bits. It must be less than 63.
+    const FRACTIONAL_BITS: u32;
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit: FixedScale> Amount<TF, Unit, i64> {
+    /// Converts from [f64], rounding to the nearest representable value (with ties away from
+    /// zero). Like `as` casts do, this saturates at the numeric bounds (including for infinities),
+    /// and maps NaN to zero.
+    pub fn from_f64(value: f64) -> Self {
+        let scaled = value * Self::one_as_f64();
+        // `f64::round` is not in `core`. Adding ±0.5 before truncating would round (in `f64`)
+        // itself, so truncate first. The remaining fraction is exact (and zero from 2^52 up).
+        let truncated = scaled as i64;
+        let fraction = scaled - truncated as f64;
+        Self::new(if fraction >= 0.5 {
+            truncated.saturating_add(1)
+        } else if fraction <= -0.5 {
+            truncated.saturating_sub(1)
+        } else {
+            truncated
+        })
+    }
+
+    /// Converts to [f64]. This is exact, unless the `Repr` has more than 53 significant bits.
+    pub fn to_f64(&self) -> f64 {
+        *self.get_ref() as f64 / Self::one_as_f64()
+    }
+
+    /// Multiplies two fixed point numbers, shifting the (wider) product back by
+    /// `FRACTIONAL_BITS`. The result is rounded down (towards negative infinity), like an
+    /// arithmetic right shift does.
+    ///
+    /// # Panics
+    ///
+    /// If the result overflows `i64`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn mul_fixed(self, other: Self) -> Self {
+        let product = i128::from(*self.get_ref()) * i128::from(*other.get_ref());
+        Self::new(
+            i64::try_from(product >> Unit::FRACTIONAL_BITS)
+                .expect("overflow when multiplying fixed point amounts"),
+        )
+    }
+
+    fn one_as_f64() -> f64 {
+        (1_u64 << Unit::FRACTIONAL_BITS) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    enum FixedQ16 {}
+    impl FixedScale for FixedQ16 {
+        const FRACTIONAL_BITS: u32 = 16;
+    }
+    type Q16 = Amount<FixedQ16, i64>;
+
+    #[test]
+    fn test_representable() {
+        assert_eq!(Q16::from_f64(1.0), Q16::from(65_536));
+        assert_eq!(Q16::from_f64(-2.25), Q16::from(-147_456));
+        assert_eq!(Q16::from_f64(0.0), Q16::from(0));
+        assert_eq!(Q16::from(98_304).to_f64(), 1.5);
+        assert_eq!(Q16::from_f64(-2.25).to_f64(), -2.25);
+    }
+
+    #[test]
+    fn test_rounding() {
+        // One bit is 1/65536; a third of it rounds down, two thirds round up.
+        let lsb = 1.0 / 65_536.0;
+        assert_eq!(Q16::from_f64(lsb / 3.0), Q16::from(0));
+        assert_eq!(Q16::from_f64(lsb * 2.0 / 3.0), Q16::from(1));
+        assert_eq!(Q16::from_f64(-lsb * 2.0 / 3.0), Q16::from(-1));
+        assert_eq!(Q16::from_f64(f64::NAN), Q16::from(0));
+
+        // Ties away from zero.
+        assert_eq!(Q16::from_f64(lsb / 2.0), Q16::from(1));
+        assert_eq!(Q16::from_f64(-lsb / 2.0), Q16::from(-1));
+        assert_eq!(Q16::from_f64(lsb * 2.5), Q16::from(3));
+        // The largest `f64` below 0.5 (of one bit): `+ 0.5` would round up to 1.0.
+        assert_eq!(Q16::from_f64(0.499_999_999_999_999_94 * lsb), Q16::from(0));
+        assert_eq!(Q16::from_f64(-0.499_999_999_999_999_94 * lsb), Q16::from(0));
+    }
+
+    #[test]
+    fn test_large_magnitude() {
+        // From 2^52 up, `f64`s are integers, and `+ 0.5` would round to even.
+        let odd = (1_i64 << 52) + 1;
+        assert_eq!(Q16::from_f64(odd as f64 / 65_536.0), Q16::from(odd));
+        assert_eq!(Q16::from_f64(-odd as f64 / 65_536.0), Q16::from(-odd));
+        let big = (1_i64 << 62) + (1 << 10);
+        assert_eq!(Q16::from_f64(big as f64 / 65_536.0), Q16::from(big));
+    }
+
+    #[test]
+    fn test_saturating() {
+        assert_eq!(Q16::from_f64(1e300), Q16::from(i64::MAX));
+        assert_eq!(Q16::from_f64(-1e300), Q16::from(i64::MIN));
+        assert_eq!(Q16::from_f64(f64::INFINITY), Q16::from(i64::MAX));
+        assert_eq!(Q16::from_f64(f64::NEG_INFINITY), Q16::from(i64::MIN));
+        // 2^63 (the nearest `f64` above `i64::MAX`).
+        assert_eq!(Q16::from_f64(140_737_488_355_328.0), Q16::from(i64::MAX));
+        assert_eq!(Q16::from_f64(-140_737_488_355_328.0), Q16::from(i64::MIN));
+    }
+
+    #[test]
+    fn test_mul_fixed() {
+        // Without the shift correction, 2.5 * 4.0 would be 2^16 times too big.
+        let product = Q16::from_f64(2.5).mul_fixed(Q16::from_f64(4.0));
+        assert_eq!(product, Q16::from_f64(10.0));
+        assert_eq!(
+            Q16::from_f64(-1.5).mul_fixed(Q16::from_f64(0.5)).to_f64(),
+            -0.75
+        );
+        // 1/65536 squared is below the resolution: rounded down, even when negative.
+        assert_eq!(Q16::from(1).mul_fixed(Q16::from(1)), Q16::from(0));
+        assert_eq!(Q16::from(-1).mul_fixed(Q16::from(1)), Q16::from(-1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mul_fixed_overflow() {
+        let _ = Q16::from(i64::MAX).mul_fixed(Q16::from(i64::MAX));
+    }
+}
@@ -13,10 +13,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::displayer::{DisplayProxy, DisplayerOf};
+use crate::displayer::{DisplayProxy, DisplayWithProxy, DisplayerOf, DisplayerWith};
+use crate::ops::ByteOps;
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
 use crate::trait_flag::{self, TraitFlags};
+use core::any::TypeId;
+use core::array::TryFromSliceError;
+use core::borrow::Borrow;
 use core::cmp::Ordering;
+use core::convert::TryFrom;
 use core::fmt;
 use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
@@ -100,6 +105,30 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// assert!(users_by_id.get(&id).is_some());
 /// ```
 ///
+/// An `Id` hashes (and compares) exactly like its `Repr`, no matter
+/// the trait flags. That is guaranteed, and `Id` implements
+/// `Borrow<Repr>`, so maps keyed by ids can be looked up by a bare
+/// `Repr`, too:
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::Id;
+/// use std::collections::HashMap;
+///
+/// enum User {}
+/// type UserId = Id<User, u64>;
+///
+/// let mut names = HashMap::new();
+/// names.insert(UserId::from(7), "john");
+///
+/// assert_eq!(names.get(&7u64), Some(&"john"));
+/// assert_eq!(names.get(&UserId::from(7)), Some(&"john"));
+/// ```
+///
 /// Ids are ordered if the `Repr` is. Note that this is mostly useful
 /// e.g. for storing Ids in a `BTreeMap`, there is usually little
 /// semantic value in comparing ids.
@@ -124,6 +153,26 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// assert!(map.get(&id).is_some());
 /// ```
 ///
+/// Ids can be formatted in hexadecimal, octal and binary if the
+/// `Repr` can. Formatting flags (like width and `#`) are respected:
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::Id;
+///
+/// enum Device {}
+///
+/// let x = Id::<Device, u32>::from(5);
+/// assert_eq!(format!("{:#010b}", x), "0b00000101");
+/// assert_eq!(format!("{:#x}", x), "0x5");
+/// assert_eq!(format!("{:X}", Id::<Device, u32>::from(255)), "FF");
+/// assert_eq!(format!("{:o}", Id::<Device, u32>::from(8)), "10");
+/// ```
+///
 /// Ids can be sent between threads if the `Repr` allows it, no
 /// matter which `Entity` is used.
 ///
@@ -187,10 +236,44 @@ impl<const TF: TraitFlags, Entity, Repr> Id<TF, Entity, Repr> {
     ///
     /// assert_eq!(*UserId::from(15).get(), 15);
     /// ```
+    #[must_use]
     pub const fn get(&self) -> &Repr {
         &self.0
     }
 
+    /// A copy of the underlying representation, for use as a key of a
+    /// map (or a set) that holds the typed id elsewhere, like in the
+    /// value. The same as `*self.get()`, but it says why.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Id;
+    /// use std::collections::BTreeMap;
+    ///
+    /// struct User {
+    ///     id: Id<User, u64>,
+    ///     name: &'static str,
+    /// }
+    ///
+    /// let users = vec![
+    ///     User { id: Id::from(7), name: "Ann" },
+    ///     User { id: Id::from(3), name: "Bob" },
+    /// ];
+    /// let by_id: BTreeMap<_, _> = users.iter().map(|user| (user.id.key(), user)).collect();
+    /// assert_eq!(by_id.keys().copied().collect::<Vec<u64>>(), [3, 7]);
+    /// assert_eq!(by_id[&7].name, "Ann");
+    /// ```
+    pub fn key(&self) -> Repr
+    where
+        Repr: Copy,
+    {
+        self.0
+    }
+
     /// `new` is a synonym for `from` that can be evaluated in
     /// compile time. The main use-case of this functions is defining
     /// constants:
@@ -212,6 +295,281 @@ impl<const TF: TraitFlags, Entity, Repr> Id<TF, Entity, Repr> {
     pub const fn new(repr: Repr) -> Id<TF, Entity, Repr> {
         Id(repr, PhantomData)
     }
+
+    /// Creates an id of `Repr::default()` (zero for numbers). Unlike
+    /// [Default], it's available for all trait flags, including the
+    /// `*NoDefault` ones.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::IdNoDefault;
+    ///
+    /// enum User {}
+    ///
+    /// assert_eq!(IdNoDefault::<User, u32>::zeroed(), IdNoDefault::from(0));
+    /// ```
+    pub fn zeroed() -> Self
+    where
+        Repr: Default,
+    {
+        Self::new(Repr::default())
+    }
+
+    /// The same as `get`. For consistency with `Amount` and `Instant`.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::IdNoCopy;
+    ///
+    /// enum Blob {}
+    ///
+    /// let id = IdNoCopy::<Blob, Vec<u8>>::from(vec![1, 2, 3]);
+    /// assert_eq!(id.get_ref(), &[1, 2, 3]);
+    /// ```
+    pub const fn get_ref(&self) -> &Repr {
+        &self.0
+    }
+
+    /// Mutably borrows the wrapped value, to modify it in place
+    /// (without reconstructing the id). Like for any key, don't modify
+    /// an id while it's in a map or a set.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::IdNoCopy;
+    ///
+    /// enum Blob {}
+    ///
+    /// let mut id = IdNoCopy::<Blob, Vec<u8>>::from(vec![1, 2]);
+    /// id.get_mut().push(3);
+    /// assert_eq!(id.get_ref(), &[1, 2, 3]);
+    /// ```
+    pub fn get_mut(&mut self) -> &mut Repr {
+        &mut self.0
+    }
+
+    /// Consumes `self` and returns the underlying representation,
+    /// without cloning it.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::IdNoCopy;
+    ///
+    /// enum User {}
+    ///
+    /// let id = IdNoCopy::<User, String>::from("john".to_string());
+    /// let s: String = id.into_inner();
+    /// assert_eq!(s, "john");
+    /// ```
+    #[must_use]
+    pub fn into_inner(self) -> Repr {
+        self.0
+    }
+
+    /// Reinterprets a slice of `Repr`s as a slice of ids, without
+    /// copying.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Id;
+    ///
+    /// enum Node {}
+    ///
+    /// let reprs: &[u64] = &[1, 2, 3];
+    /// let ids: &[Id<Node, u64>] = Id::cast_slice(reprs);
+    /// assert_eq!(ids[2], Id::from(3));
+    /// ```
+    pub fn cast_slice(reprs: &[Repr]) -> &[Self] {
+        let () = Self::SAME_LAYOUT_AS_REPR;
+        // SAFETY: `Self` is `#[repr(transparent)]` over `Repr` (the other
+        // field is a zero-sized `PhantomData`), so it has the same size,
+        // alignment and validity as `Repr`. The lifetime is preserved.
+        unsafe { core::slice::from_raw_parts(reprs.as_ptr().cast::<Self>(), reprs.len()) }
+    }
+
+    /// Mutable version of [Self::cast_slice].
+    pub fn cast_slice_mut(reprs: &mut [Repr]) -> &mut [Self] {
+        let () = Self::SAME_LAYOUT_AS_REPR;
+        // SAFETY: See `cast_slice`. The exclusive borrow is preserved.
+        unsafe { core::slice::from_raw_parts_mut(reprs.as_mut_ptr().cast::<Self>(), reprs.len()) }
+    }
+
+    /// Compile-time assertion (evaluated once used) that `Self` and
+    /// `Repr` have the same layout, as guaranteed by
+    /// `#[repr(transparent)]`. (Indexing out of bounds fails the
+    /// evaluation: `assert!` in `const` context needs Rust 1.57.)
+    const SAME_LAYOUT_AS_REPR: () = [()][(core::mem::size_of::<Self>()
+        != core::mem::size_of::<Repr>()
+        || core::mem::align_of::<Self>() != core::mem::align_of::<Repr>())
+        as usize];
+
+    /// Transforms the underlying representation, keeping the same
+    /// entity.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Id;
+    ///
+    /// enum User {}
+    ///
+    /// let id = Id::<User, u32>::from(15);
+    /// assert_eq!(id.map(u64::from), Id::<User, u64>::from(15));
+    /// ```
+    #[must_use]
+    pub fn map<R2>(self, f: impl FnOnce(Repr) -> R2) -> Id<TF, Entity, R2> {
+        Id::new(f(self.0))
+    }
+
+    /// Fallible version of [Self::map].
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use core::convert::TryFrom;
+    /// use phantom_newtype::Id;
+    ///
+    /// enum User {}
+    ///
+    /// let id = Id::<User, u64>::from(15);
+    /// assert_eq!(id.try_map(u8::try_from), Ok(Id::<User, u8>::from(15)));
+    ///
+    /// let id = Id::<User, u64>::from(256);
+    /// assert!(id.try_map(u8::try_from).is_err());
+    /// ```
+    pub fn try_map<R2, E>(
+        self,
+        f: impl FnOnce(Repr) -> Result<R2, E>,
+    ) -> Result<Id<TF, Entity, R2>, E> {
+        f(self.0).map(Id::new)
+    }
+
+    /// Changes the trait flags (like whether `Self` is `Copy`), moving
+    /// the underlying representation. The layout is the same for all
+    /// flags, so this is only a compile-time re-tag. The target flags
+    /// are best inferred from the target type alias:
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::{Id, IdNoCopy, IdNoDefault};
+    ///
+    /// enum User {}
+    ///
+    /// let x = IdNoCopy::<User, u64>::from(7);
+    /// let y: IdNoDefault<User, u64> = x.with_flags();
+    /// let z: Id<User, u64> = y.with_flags();
+    /// assert_eq!(z, Id::from(7));
+    /// ```
+    #[must_use]
+    pub fn with_flags<const TF2: TraitFlags>(self) -> Id<TF2, Entity, Repr> {
+        Id::new(self.0)
+    }
+
+    /// Like `display`, but with a context (like a locale) passed to the
+    /// [DisplayerWith] implementation of the entity.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use core::fmt;
+    /// use phantom_newtype::{DisplayerWith, Id};
+    ///
+    /// struct Order;
+    /// type OrderId = Id<Order, u32>;
+    ///
+    /// enum Audience {
+    ///     Customer,
+    ///     Support,
+    /// }
+    ///
+    /// impl DisplayerWith<OrderId, Audience> for Order {
+    ///     fn display_with(id: &OrderId, audience: &Audience, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         match audience {
+    ///             Audience::Customer => write!(f, "#{:06}", id.get()),
+    ///             Audience::Support => write!(f, "order/{}", id.get()),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let id = OrderId::from(42);
+    /// assert_eq!(format!("{}", id.display_with(Audience::Customer)), "#000042");
+    /// assert_eq!(format!("{}", id.display_with(Audience::Support)), "order/42");
+    /// ```
+    pub fn display_with<Ctx>(&self, ctx: Ctx) -> DisplayWithProxy<'_, Self, Ctx, Entity>
+    where
+        Entity: DisplayerWith<Self, Ctx>,
+    {
+        DisplayWithProxy::new(self, ctx)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<Entity, Repr> Id<{ trait_flag::TRAIT_FLAGS_NO_COPY_IS_DEFAULT }, Entity, Repr> {
+    /// Converts to the `Copy` variant, keeping whether it's `Default`.
+    #[must_use]
+    pub fn into_copy(self) -> Id<{ trait_flag::TRAIT_FLAGS_IS_COPY_IS_DEFAULT }, Entity, Repr> {
+        self.with_flags()
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<Entity, Repr> Id<{ trait_flag::TRAIT_FLAGS_NO_COPY_NO_DEFAULT }, Entity, Repr> {
+    /// Converts to the `Copy` variant, keeping whether it's `Default`.
+    #[must_use]
+    pub fn into_copy(self) -> Id<{ trait_flag::TRAIT_FLAGS_IS_COPY_NO_DEFAULT }, Entity, Repr> {
+        self.with_flags()
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<Entity, Repr> Id<{ trait_flag::TRAIT_FLAGS_IS_COPY_IS_DEFAULT }, Entity, Repr> {
+    /// Converts to the non-`Copy` variant, keeping whether it's `Default`.
+    #[must_use]
+    pub fn into_no_copy(self) -> Id<{ trait_flag::TRAIT_FLAGS_NO_COPY_IS_DEFAULT }, Entity, Repr> {
+        self.with_flags()
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<Entity, Repr> Id<{ trait_flag::TRAIT_FLAGS_IS_COPY_NO_DEFAULT }, Entity, Repr> {
+    /// Converts to the non-`Copy` variant, keeping whether it's `Default`.
+    #[must_use]
+    pub fn into_no_copy(self) -> Id<{ trait_flag::TRAIT_FLAGS_NO_COPY_NO_DEFAULT }, Entity, Repr> {
+        self.with_flags()
+    }
 }
 
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
@@ -290,21 +648,44 @@ impl<Unit, Repr: Default> Default
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
 impl<const TF: TraitFlags, Entity, Repr: PartialEq> PartialEq for Id<TF, Entity, Repr> {
     fn eq(&self, rhs: &Self) -> bool {
-        self.get().eq(&rhs.get())
+        self.get().eq(rhs.get())
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity, Repr: PartialEq> Id<TF, Entity, Repr> {
+    /// Whether `self` and `other` have equal `Repr`s, even though they may differ in trait flags
+    /// (like when mixing `Copy` and non-`Copy` ids from different subsystems).
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::{Id, IdNoCopyNoDefault};
+    ///
+    /// enum User {}
+    ///
+    /// let id = Id::<User, u64>::from(1);
+    /// assert!(id.same_value(&IdNoCopyNoDefault::from(1)));
+    /// ```
+    pub fn same_value<const TF2: TraitFlags>(&self, other: &Id<TF2, Entity, Repr>) -> bool {
+        self.get() == other.get()
     }
 }
 
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
 impl<const TF: TraitFlags, Entity, Repr: PartialOrd> PartialOrd for Id<TF, Entity, Repr> {
     fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
-        self.get().partial_cmp(&rhs.get())
+        self.get().partial_cmp(rhs.get())
     }
 }
 
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
 impl<const TF: TraitFlags, Entity, Repr: Ord> Ord for Id<TF, Entity, Repr> {
     fn cmp(&self, rhs: &Self) -> Ordering {
-        self.get().cmp(&rhs.get())
+        self.get().cmp(rhs.get())
     }
 }
 
@@ -315,6 +696,91 @@ impl<const TF: TraitFlags, Entity, Repr: Hash> Hash for Id<TF, Entity, Repr> {
     }
 }
 
+// Sound because `Hash`, `Eq` and `Ord` of `Id` all forward to `Repr`.
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity, Repr> Borrow<Repr> for Id<TF, Entity, Repr> {
+    fn borrow(&self) -> &Repr {
+        &self.0
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity: 'static, Repr: Hash> Id<TF, Entity, Repr> {
+    /// Hashes the [TypeId] of `Entity` along with the `Repr`, unlike [Hash] of `Self` (which
+    /// hashes just like `Repr`). For key spaces shared by ids of several entities (like a map
+    /// keyed by type-erased ids), where ids of different entities with equal `Repr`s should not
+    /// collide.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Id;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// enum User {}
+    /// enum Post {}
+    ///
+    /// fn hash_of(value: impl Hash) -> u64 {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     value.hash(&mut hasher);
+    ///     hasher.finish()
+    /// }
+    ///
+    /// let (user, post) = (Id::<User, u64>::from(1), Id::<Post, u64>::from(1));
+    /// assert_eq!(hash_of(user), hash_of(post));
+    /// assert_ne!(hash_of(user.hash_with_unit()), hash_of(post.hash_with_unit()));
+    /// ```
+    pub fn hash_with_unit(&self) -> impl Hash + '_ {
+        HashWithUnit(self)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+struct HashWithUnit<'a, const TF: TraitFlags, Entity, Repr>(&'a Id<TF, Entity, Repr>);
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity: 'static, Repr: Hash> Hash
+    for HashWithUnit<'_, TF, Entity, Repr>
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        TypeId::of::<Entity>().hash(state);
+        self.0 .0.hash(state)
+    }
+}
+
+/// Borrows the underlying representation, so that an id can be
+/// passed where `impl AsRef<Repr>` is expected.
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::Id;
+///
+/// enum Document {}
+/// type DocumentId = Id<Document, [u8; 16]>;
+///
+/// fn checksum(bytes: impl AsRef<[u8; 16]>) -> u32 {
+///     bytes.as_ref().iter().map(|&b| u32::from(b)).sum()
+/// }
+///
+/// let id = DocumentId::from([1; 16]);
+/// assert_eq!(checksum(&id), 16);
+/// assert_eq!(checksum(id), 16);
+/// ```
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity, Repr> AsRef<Repr> for Id<TF, Entity, Repr> {
+    fn as_ref(&self) -> &Repr {
+        &self.0
+    }
+}
+
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
 impl<const TF: TraitFlags, Entity, Repr> From<Repr> for Id<TF, Entity, Repr> {
     fn from(repr: Repr) -> Self {
@@ -322,6 +788,58 @@ impl<const TF: TraitFlags, Entity, Repr> From<Repr> for Id<TF, Entity, Repr> {
     }
 }
 
+/// Unwraps a byte array id (like a digest). The other direction is covered by `From<Repr>`.
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::Id;
+///
+/// enum Sha1 {}
+/// type Sha1Id = Id<Sha1, [u8; 20]>;
+///
+/// let id = Sha1Id::from([7; 20]);
+/// let bytes: [u8; 20] = id.into();
+/// assert_eq!(bytes, [7; 20]);
+/// ```
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity, const N: usize> From<Id<TF, Entity, [u8; N]>> for [u8; N] {
+    fn from(id: Id<TF, Entity, [u8; N]>) -> Self {
+        id.0
+    }
+}
+
+/// Copies a slice into a byte array id (like a digest), failing unless the slice length is `N`.
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use core::convert::TryFrom;
+/// use phantom_newtype::Id;
+///
+/// enum Digest {}
+/// type DigestId = Id<Digest, [u8; 4]>;
+///
+/// let bytes = [0xde, 0xad, 0xbe, 0xef, 0x00];
+/// let id = DigestId::try_from(&bytes[..4]).unwrap();
+/// assert_eq!(id, DigestId::from([0xde, 0xad, 0xbe, 0xef]));
+/// assert!(DigestId::try_from(&bytes[..]).is_err());
+/// ```
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity, const N: usize> TryFrom<&[u8]> for Id<TF, Entity, [u8; N]> {
+    type Error = TryFromSliceError;
+
+    fn try_from(slice: &[u8]) -> Result<Self, TryFromSliceError> {
+        <[u8; N]>::try_from(slice).map(Self::new)
+    }
+}
+
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
 impl<const TF: TraitFlags, Entity, Repr: Eq> Eq for Id<TF, Entity, Repr> {}
 
@@ -339,6 +857,34 @@ impl<const TF: TraitFlags, Entity, Repr: fmt::Display> fmt::Display for Id<TF, E
     }
 }
 
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity, Repr: fmt::LowerHex> fmt::LowerHex for Id<TF, Entity, Repr> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity, Repr: fmt::UpperHex> fmt::UpperHex for Id<TF, Entity, Repr> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity, Repr: fmt::Octal> fmt::Octal for Id<TF, Entity, Repr> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Octal::fmt(&self.0, f)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity, Repr: fmt::Binary> fmt::Binary for Id<TF, Entity, Repr> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(&self.0, f)
+    }
+}
+
 #[cfg(feature = "serde")]
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
 impl<const TF: TraitFlags, Entity, Repr> Serialize for Id<TF, Entity, Repr>
@@ -360,3 +906,494 @@ where
         Repr::deserialize(deserializer).map(Self::from)
     }
 }
+
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<'a, const TF: TraitFlags, Entity: 'a, Repr> arbitrary::Arbitrary<'a> for Id<TF, Entity, Repr>
+where
+    Repr: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Repr::arbitrary(u).map(Self::new)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Repr::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity: 'static, Repr> quickcheck::Arbitrary for Id<TF, Entity, Repr>
+where
+    Repr: quickcheck::Arbitrary,
+{
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Self::new(Repr::arbitrary(g))
+    }
+
+    fn shrink(&self) -> alloc::boxed::Box<dyn Iterator<Item = Self>> {
+        alloc::boxed::Box::new(self.0.shrink().map(Self::new))
+    }
+}
+
+// Like with `serde`, the encoded forms of `Id` and `Repr` are identical.
+#[cfg(feature = "borsh")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity, Repr> borsh::BorshSerialize for Id<TF, Entity, Repr>
+where
+    Repr: borsh::BorshSerialize,
+{
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity, Repr> borsh::BorshDeserialize for Id<TF, Entity, Repr>
+where
+    Repr: borsh::BorshDeserialize,
+{
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        Repr::deserialize_reader(reader).map(Self::new)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity, Repr: ByteOps> Id<TF, Entity, Repr> {
+    /// Size of the byte representation, in bytes.
+    pub const BYTES: usize = Repr::BYTES;
+
+    /// The `Repr` as bytes in big-endian (network) byte order, like `u64::to_be_bytes`.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Id;
+    ///
+    /// enum Msg {}
+    /// type MsgId = Id<Msg, u32>;
+    ///
+    /// let bytes: [u8; MsgId::BYTES] = MsgId::from(0x0102_0304).to_be_bytes();
+    /// assert_eq!(bytes, [1, 2, 3, 4]);
+    /// assert_eq!(MsgId::from_be_bytes(bytes), MsgId::from(0x0102_0304));
+    /// ```
+    pub fn to_be_bytes(&self) -> Repr::Bytes {
+        self.0.to_be_bytes()
+    }
+
+    /// The `Repr` as bytes in little-endian byte order.
+    pub fn to_le_bytes(&self) -> Repr::Bytes {
+        self.0.to_le_bytes()
+    }
+
+    /// Creates an id from its big-endian byte representation.
+    pub fn from_be_bytes(bytes: Repr::Bytes) -> Self {
+        Self::new(Repr::from_be_bytes(bytes))
+    }
+
+    /// Creates an id from its little-endian byte representation.
+    pub fn from_le_bytes(bytes: Repr::Bytes) -> Self {
+        Self::new(Repr::from_le_bytes(bytes))
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity, const N: usize> Id<TF, Entity, [u8; N]> {
+    /// Displays the bytes as lowercase hex (two digits per byte), without allocating. For
+    /// content-addressed ids (like digests).
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Id;
+    ///
+    /// enum Blob {}
+    /// type BlobId = Id<Blob, [u8; 4]>;
+    ///
+    /// let id = BlobId::from([0xde, 0xad, 0x00, 0x0f]);
+    /// assert_eq!(id.to_hex().to_string(), "dead000f");
+    /// assert_eq!(BlobId::from_hex("dead000f"), Ok(id));
+    /// assert_eq!(BlobId::from_hex("DEAD000F"), Ok(id));
+    /// assert!(BlobId::from_hex("dead").is_err());
+    /// ```
+    pub fn to_hex(&self) -> impl fmt::Display + '_ {
+        Hex(&self.0)
+    }
+
+    /// Parses `2 * N` hex digits (either case), as written by [Id::to_hex].
+    pub fn from_hex(hex: &str) -> Result<Self, FromHexError> {
+        if hex.len() != 2 * N {
+            return Err(FromHexError::Length {
+                expected: 2 * N,
+                actual: hex.len(),
+            });
+        }
+        let digit = |index: usize| {
+            (hex.as_bytes()[index] as char)
+                .to_digit(16)
+                .map(|digit| digit as u8)
+                .ok_or(FromHexError::Digit { index })
+        };
+        let mut bytes = [0; N];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = digit(2 * i)? << 4 | digit(2 * i + 1)?;
+        }
+        Ok(Self::new(bytes))
+    }
+}
+
+struct Hex<'a, const N: usize>(&'a [u8; N]);
+
+impl<const N: usize> fmt::Display for Hex<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{:02x}", byte))
+    }
+}
+
+/// Error of `Id::from_hex`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FromHexError {
+    /// The input doesn't have exactly two digits per byte.
+    Length { expected: usize, actual: usize },
+    /// The (byte) `index` of the input is not a hex digit.
+    Digit { index: usize },
+}
+
+impl fmt::Display for FromHexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Length { expected, actual } => {
+                write!(f, "expected {} hex digits, got {}", expected, actual)
+            }
+            Self::Digit { index } => write!(f, "invalid hex digit at index {}", index),
+        }
+    }
+}
+
+#[cfg(feature = "uuid")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity> Id<TF, Entity, u128> {
+    /// Creates an id with the same 128 bits as the given [uuid::Uuid].
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Id;
+    /// use uuid::Uuid;
+    ///
+    /// enum User {}
+    /// type UserId = Id<User, u128>;
+    ///
+    /// let uuid = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+    /// let id = UserId::from_uuid(uuid);
+    /// assert_eq!(id.get(), &0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+    /// assert_eq!(id.to_uuid(), uuid);
+    /// ```
+    pub const fn from_uuid(uuid: uuid::Uuid) -> Self {
+        Self(uuid.as_u128(), PhantomData)
+    }
+
+    /// Returns the [uuid::Uuid] with the same 128 bits.
+    pub const fn to_uuid(&self) -> uuid::Uuid {
+        uuid::Uuid::from_u128(self.0)
+    }
+}
+
+/// Parses any form accepted by [uuid::Uuid::parse_str], including the canonical hyphenated one.
+#[cfg(feature = "uuid")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity> core::str::FromStr for Id<TF, Entity, u128> {
+    type Err = uuid::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        uuid::Uuid::parse_str(s).map(Self::from_uuid)
+    }
+}
+
+#[cfg(feature = "rand")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity, Repr> Id<TF, Entity, Repr>
+where
+    rand::distributions::Standard: rand::distributions::Distribution<Repr>,
+{
+    /// Generates a random id, with `Repr` sampled from
+    /// [rand::distributions::Standard]. Equivalent to `rng.gen()`.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Id;
+    /// use rand::{rngs::SmallRng, SeedableRng};
+    ///
+    /// enum Session {}
+    /// type SessionId = Id<Session, u64>;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(7);
+    /// let first = SessionId::random(&mut rng);
+    /// let second = SessionId::random(&mut rng);
+    /// assert_ne!(first, second);
+    /// ```
+    pub fn random<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(rng.gen())
+    }
+}
+
+#[cfg(feature = "rand")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Entity, Repr> rand::distributions::Distribution<Id<TF, Entity, Repr>>
+    for rand::distributions::Standard
+where
+    rand::distributions::Standard: rand::distributions::Distribution<Repr>,
+{
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Id<TF, Entity, Repr> {
+        Id::new(self.sample(rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::*;
+    use core::hash::{Hash, Hasher};
+    use std::collections::hash_map::DefaultHasher;
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    enum Session {}
+
+    // Re-tags an id from `TF1` to `TF2`, and checks that equality and hash are preserved.
+    #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+    fn check_flag_pair<const TF1: trait_flag::TraitFlags, const TF2: trait_flag::TraitFlags>() {
+        let a = id::Id::<TF1, Session, u64>::new(42);
+        let b: id::Id<TF2, Session, u64> = id::Id::<TF1, Session, u64>::new(42).with_flags();
+        assert!(a.same_value(&b));
+        assert!(b.same_value(&a));
+        assert_eq!(hash_of(&a), hash_of(&b));
+        assert!(!a.same_value(&id::Id::<TF2, Session, u64>::new(43)));
+
+        let back: id::Id<TF1, Session, u64> = b.with_flags();
+        assert!(back == a);
+        assert_eq!(hash_of(&back), hash_of(&a));
+    }
+
+    #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+    fn check_flags_from<const TF1: trait_flag::TraitFlags>() {
+        use trait_flag::*;
+        check_flag_pair::<TF1, TRAIT_FLAGS_IS_COPY_IS_DEFAULT>();
+        check_flag_pair::<TF1, TRAIT_FLAGS_IS_COPY_NO_DEFAULT>();
+        check_flag_pair::<TF1, TRAIT_FLAGS_NO_COPY_IS_DEFAULT>();
+        check_flag_pair::<TF1, TRAIT_FLAGS_NO_COPY_NO_DEFAULT>();
+    }
+
+    #[test]
+    #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+    fn test_same_value_all_flag_pairs() {
+        use trait_flag::*;
+        check_flags_from::<TRAIT_FLAGS_IS_COPY_IS_DEFAULT>();
+        check_flags_from::<TRAIT_FLAGS_IS_COPY_NO_DEFAULT>();
+        check_flags_from::<TRAIT_FLAGS_NO_COPY_IS_DEFAULT>();
+        check_flags_from::<TRAIT_FLAGS_NO_COPY_NO_DEFAULT>();
+    }
+
+    #[test]
+    fn test_hash_same_as_repr() {
+        enum User {}
+
+        let repr = 0x1234_5678_u64;
+        assert_eq!(hash_of(&Id::<User, u64>::from(repr)), hash_of(&repr));
+        assert_eq!(hash_of(&IdNoCopy::<User, u64>::from(repr)), hash_of(&repr));
+        assert_eq!(
+            hash_of(&IdNoDefault::<User, u64>::from(repr)),
+            hash_of(&repr)
+        );
+        assert_eq!(
+            hash_of(&IdNoCopyNoDefault::<User, u64>::from(repr)),
+            hash_of(&repr)
+        );
+    }
+
+    #[test]
+    fn test_borrow_lookup() {
+        use std::collections::{BTreeSet, HashMap};
+
+        enum User {}
+        type UserId = IdNoCopy<User, u64>;
+
+        let mut map = HashMap::new();
+        map.insert(UserId::from(1), 'a');
+        map.insert(UserId::from(2), 'b');
+        assert_eq!(map.get(&2u64), Some(&'b'));
+        assert_eq!(map.get(&3u64), None);
+
+        let set: BTreeSet<_> = core::iter::once(UserId::from(5)).collect();
+        assert!(set.contains(&5u64));
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn test_uuid() {
+        enum User {}
+        type UserId = Id<User, u128>;
+
+        const CANONICAL: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+        let id: UserId = CANONICAL.parse().unwrap();
+        assert_eq!(id.get(), &0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+        assert_eq!(
+            id.to_uuid()
+                .hyphenated()
+                .encode_lower(&mut uuid::Uuid::encode_buffer()),
+            CANONICAL
+        );
+        assert_eq!(UserId::from_uuid(id.to_uuid()), id);
+        assert!("not-a-uuid".parse::<UserId>().is_err());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random() {
+        use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+        enum Session {}
+        type SessionId = Id<Session, u64>;
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        let mut ids = [SessionId::new(0); 1_000];
+        for (i, id) in ids.iter_mut().enumerate() {
+            *id = if i % 2 == 0 {
+                SessionId::random(&mut rng)
+            } else {
+                rng.gen()
+            };
+        }
+        ids.sort_unstable();
+        assert!(ids.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    fn test_try_from_slice() {
+        use core::convert::TryFrom;
+
+        enum Digest {}
+        type DigestId = Id<Digest, [u8; 32]>;
+
+        let bytes: std::vec::Vec<u8> = (0..40).collect();
+        let id = DigestId::try_from(&bytes[..32]).unwrap();
+        assert_eq!(id.get()[..], bytes[..32]);
+        assert!(DigestId::try_from(&bytes[..31]).is_err());
+        assert!(DigestId::try_from(&bytes[..33]).is_err());
+        assert!(DigestId::try_from(&[][..]).is_err());
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        enum Msg {}
+
+        let id = Id::<Msg, u16>::from(0xabcd);
+        assert_eq!(id.to_be_bytes(), [0xab, 0xcd]);
+        assert_eq!(id.to_le_bytes(), [0xcd, 0xab]);
+        assert_eq!(Id::from_be_bytes(id.to_be_bytes()), id);
+        assert_eq!(Id::from_le_bytes(id.to_le_bytes()), id);
+
+        let id = IdNoCopy::<Msg, u32>::from(0x0102_0304);
+        assert_eq!(id.to_be_bytes(), [1, 2, 3, 4]);
+        assert_eq!(IdNoCopy::from_be_bytes(id.to_be_bytes()), id);
+        assert_eq!(IdNoCopy::from_le_bytes(id.to_le_bytes()), id);
+
+        let id = Id::<Msg, u64>::from(u64::MAX - 1);
+        assert_eq!(Id::<Msg, u64>::BYTES, 8);
+        assert_eq!(id.to_le_bytes()[0], 0xfe);
+        assert_eq!(Id::from_be_bytes(id.to_be_bytes()), id);
+        assert_eq!(Id::from_le_bytes(id.to_le_bytes()), id);
+    }
+
+    #[test]
+    fn test_hash_with_unit() {
+        enum User {}
+        enum Post {}
+
+        let user = IdNoCopy::<User, u64>::from(7);
+        let post = IdNoCopy::<Post, u64>::from(7);
+        assert_eq!(hash_of(&user), hash_of(&post));
+        assert_ne!(
+            hash_of(&user.hash_with_unit()),
+            hash_of(&post.hash_with_unit())
+        );
+        assert_eq!(
+            hash_of(&user.hash_with_unit()),
+            hash_of(&IdNoCopy::<User, u64>::from(7).hash_with_unit())
+        );
+        assert_ne!(hash_of(&user.hash_with_unit()), hash_of(&user));
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        use std::string::ToString;
+
+        enum Digest {}
+        type DigestId = Id<Digest, [u8; 32]>;
+
+        let mut bytes = [0; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = (i * 37) as u8;
+        }
+        let id = DigestId::from(bytes);
+        let hex = id.to_hex().to_string();
+        assert_eq!(hex.len(), 64);
+        assert!(hex.starts_with("00254a6f94b9"));
+        assert_eq!(DigestId::from_hex(&hex), Ok(id));
+        assert_eq!(DigestId::from_hex(&hex.to_uppercase()), Ok(id));
+
+        assert_eq!(
+            DigestId::from_hex(&hex[..62]),
+            Err(FromHexError::Length {
+                expected: 64,
+                actual: 62
+            })
+        );
+        let mut invalid = hex.clone();
+        invalid.replace_range(5..6, "g");
+        assert_eq!(
+            DigestId::from_hex(&invalid),
+            Err(FromHexError::Digit { index: 5 })
+        );
+    }
+
+    #[test]
+    fn test_byte_array_conversions() {
+        enum Sha256 {}
+        enum Blake3 {}
+
+        let bytes = [0xab; 32];
+        let sha: Id<Sha256, [u8; 32]> = bytes.into();
+        let blake = IdNoCopy::<Blake3, [u8; 32]>::from(bytes);
+        assert_eq!(sha.get(), &bytes);
+
+        let from_sha: [u8; 32] = sha.into();
+        let from_blake: [u8; 32] = blake.into();
+        assert_eq!(from_sha, bytes);
+        assert_eq!(from_blake, bytes);
+        assert_eq!(
+            <[u8; 32]>::from(Id::<Sha256, [u8; 32]>::from([1; 32])),
+            [1; 32]
+        );
+    }
+}
@@ -14,10 +14,11 @@
 // limitations under the License.
 
 use crate::amount::Amount;
-use crate::displayer::{DisplayProxy, DisplayerOf};
+use crate::displayer::{DisplayProxy, DisplayWithProxy, DisplayerOf, DisplayerWith};
+use crate::ops::{PrimitiveInteger, SaturatingOps, StepOps, WrappingOps};
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
 use crate::trait_flag::{self, TraitFlags};
-use core::cmp::Ordering;
+use core::cmp::{Ordering, Reverse};
 use core::fmt;
 use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
@@ -76,6 +77,25 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// assert_eq!(some_date - epoch, diff);
 /// assert_eq!(some_date - diff, epoch);
 /// assert_eq!(epoch + diff, some_date);
+///
+/// let mut date = epoch;
+/// date += diff;
+/// assert_eq!(date, some_date);
+/// date -= diff;
+/// assert_eq!(date, epoch);
+/// ```
+///
+/// Note the asymmetry: an amount can be added to an instant, but two
+/// instants can't be added, because adding two absolute points in time
+/// is meaningless:
+///
+/// ```compile_fail
+/// use phantom_newtype::Instant;
+///
+/// enum SecondsFromEpoch {}
+/// type UnixTime = Instant<SecondsFromEpoch, i64>;
+///
+/// let _ = UnixTime::from(100) + UnixTime::from(5);
 /// ```
 ///
 /// Direct multiplication of instants is not supported, however, you
@@ -98,6 +118,26 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// assert_eq!(3, (x * 3) / x);
 /// ```
 ///
+/// Instants can be formatted in hexadecimal, octal and binary if the
+/// `Repr` can. Formatting flags (like width and `#`) are respected:
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::Instant;
+///
+/// enum Cycles {}
+///
+/// let x = Instant::<Cycles, u32>::from(5);
+/// assert_eq!(format!("{:#010b}", x), "0b00000101");
+/// assert_eq!(format!("{:#x}", x), "0x5");
+/// assert_eq!(format!("{:X}", Instant::<Cycles, u32>::from(255)), "FF");
+/// assert_eq!(format!("{:o}", Instant::<Cycles, u32>::from(8)), "10");
+/// ```
+///
 /// Note that the unit is only available at compile time, thus using
 /// `Instant` instead of `u64` doesn't incur any runtime penalty:
 ///
@@ -202,6 +242,7 @@ impl<const TF: TraitFlags, Unit, Repr: Copy> Instant<TF, Unit, Repr> {
     /// let three_apples = Instant::<Apples, u64>::from(3);
     /// assert_eq!(9, (three_apples * 3).get());
     /// ```
+    #[must_use]
     pub fn get(&self) -> Repr {
         self.0
     }
@@ -215,6 +256,568 @@ impl<const TF: TraitFlags, Unit, Repr> Instant<TF, Unit, Repr> {
     pub const fn new(repr: Repr) -> Instant<TF, Unit, Repr> {
         Instant(repr, PhantomData)
     }
+
+    /// Creates an instant of `Repr::default()` (zero for numbers). Unlike
+    /// [Default], it's available for all trait flags, including the
+    /// `*NoDefault` ones.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::InstantNoDefault;
+    ///
+    /// enum Tick {}
+    ///
+    /// assert_eq!(InstantNoDefault::<Tick, u32>::zeroed(), InstantNoDefault::from(0));
+    /// ```
+    pub fn zeroed() -> Self
+    where
+        Repr: Default,
+    {
+        Self::new(Repr::default())
+    }
+
+    /// Returns a reference to the wrapped value. Unlike `get`, it
+    /// doesn't need `Repr` to be `Copy`.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::InstantNoCopy;
+    ///
+    /// enum Version {}
+    ///
+    /// let when = InstantNoCopy::<Version, Vec<u8>>::from(vec![1, 2, 3]);
+    /// assert_eq!(when.get_ref(), &[1, 2, 3]);
+    /// ```
+    pub const fn get_ref(&self) -> &Repr {
+        &self.0
+    }
+
+    /// Mutably borrows the wrapped value, to modify it in place
+    /// (without reconstructing the instant).
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::InstantNoCopy;
+    ///
+    /// enum Version {}
+    ///
+    /// let mut when = InstantNoCopy::<Version, Vec<u8>>::from(vec![1, 2]);
+    /// when.get_mut().push(3);
+    /// assert_eq!(when.get_ref(), &[1, 2, 3]);
+    /// ```
+    pub fn get_mut(&mut self) -> &mut Repr {
+        &mut self.0
+    }
+
+    /// Consumes `self` and returns the wrapped value. Unlike `get`, it
+    /// doesn't need `Repr` to be `Copy`.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::InstantNoCopy;
+    ///
+    /// enum Iso8601 {}
+    ///
+    /// let when = InstantNoCopy::<Iso8601, String>::from("2024-12-07".to_string());
+    /// let s: String = when.into_inner();
+    /// assert_eq!(s, "2024-12-07");
+    /// ```
+    #[must_use]
+    pub fn into_inner(self) -> Repr {
+        self.0
+    }
+
+    /// Reinterprets a slice of `Repr`s as a slice of instants, without
+    /// copying.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Instant;
+    ///
+    /// enum Ticks {}
+    ///
+    /// let reprs: &[u64] = &[1, 2, 3];
+    /// let instants: &[Instant<Ticks, u64>] = Instant::cast_slice(reprs);
+    /// assert_eq!(instants[2], Instant::from(3));
+    /// ```
+    pub fn cast_slice(reprs: &[Repr]) -> &[Self] {
+        let () = Self::SAME_LAYOUT_AS_REPR;
+        // SAFETY: `Self` is `#[repr(transparent)]` over `Repr` (the other
+        // field is a zero-sized `PhantomData`), so it has the same size,
+        // alignment and validity as `Repr`. The lifetime is preserved.
+        unsafe { core::slice::from_raw_parts(reprs.as_ptr().cast::<Self>(), reprs.len()) }
+    }
+
+    /// Mutable version of [Self::cast_slice].
+    pub fn cast_slice_mut(reprs: &mut [Repr]) -> &mut [Self] {
+        let () = Self::SAME_LAYOUT_AS_REPR;
+        // SAFETY: See `cast_slice`. The exclusive borrow is preserved.
+        unsafe { core::slice::from_raw_parts_mut(reprs.as_mut_ptr().cast::<Self>(), reprs.len()) }
+    }
+
+    /// Compile-time assertion (evaluated once used) that `Self` and
+    /// `Repr` have the same layout, as guaranteed by
+    /// `#[repr(transparent)]`. (Indexing out of bounds fails the
+    /// evaluation: `assert!` in `const` context needs Rust 1.57.)
+    const SAME_LAYOUT_AS_REPR: () = [()][(core::mem::size_of::<Self>()
+        != core::mem::size_of::<Repr>()
+        || core::mem::align_of::<Self>() != core::mem::align_of::<Repr>())
+        as usize];
+
+    /// Transforms the wrapped value, keeping the same unit.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Instant;
+    ///
+    /// enum Seconds {}
+    ///
+    /// let when = Instant::<Seconds, u32>::from(5);
+    /// assert_eq!(when.map(i64::from), Instant::<Seconds, i64>::from(5));
+    /// ```
+    #[must_use]
+    pub fn map<R2>(self, f: impl FnOnce(Repr) -> R2) -> Instant<TF, Unit, R2> {
+        Instant::new(f(self.0))
+    }
+
+    /// Fallible version of [Self::map].
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use core::convert::TryFrom;
+    /// use phantom_newtype::Instant;
+    ///
+    /// enum Seconds {}
+    ///
+    /// let when = Instant::<Seconds, i64>::from(5);
+    /// assert_eq!(when.try_map(u32::try_from), Ok(Instant::<Seconds, u32>::from(5)));
+    ///
+    /// let before_epoch = Instant::<Seconds, i64>::from(-5);
+    /// assert!(before_epoch.try_map(u32::try_from).is_err());
+    /// ```
+    pub fn try_map<R2, E>(
+        self,
+        f: impl FnOnce(Repr) -> Result<R2, E>,
+    ) -> Result<Instant<TF, Unit, R2>, E> {
+        f(self.0).map(Instant::new)
+    }
+
+    /// Changes the trait flags (like whether `Self` is `Copy`), moving
+    /// the underlying representation. The layout is the same for all
+    /// flags, so this is only a compile-time re-tag. The target flags
+    /// are best inferred from the target type alias:
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::{Instant, InstantNoCopy, InstantNoDefault};
+    ///
+    /// enum Tick {}
+    ///
+    /// let x = InstantNoCopy::<Tick, u64>::from(7);
+    /// let y: InstantNoDefault<Tick, u64> = x.with_flags();
+    /// let z: Instant<Tick, u64> = y.with_flags();
+    /// assert_eq!(z, Instant::from(7));
+    /// ```
+    #[must_use]
+    pub fn with_flags<const TF2: TraitFlags>(self) -> Instant<TF2, Unit, Repr> {
+        Instant::new(self.0)
+    }
+
+    /// Like `display`, but with a context (like a locale) passed to the
+    /// [DisplayerWith] implementation of the unit.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use core::fmt;
+    /// use phantom_newtype::{DisplayerWith, Instant};
+    ///
+    /// struct MinuteOfDay;
+    /// type WallClock = Instant<MinuteOfDay, u32>;
+    ///
+    /// /// Offset from UTC, in minutes.
+    /// struct TimeZone(u32);
+    ///
+    /// impl DisplayerWith<WallClock, TimeZone> for MinuteOfDay {
+    ///     fn display_with(utc: &WallClock, zone: &TimeZone, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         let local = (utc.get() + zone.0) % (24 * 60);
+    ///         write!(f, "{:02}:{:02}", local / 60, local % 60)
+    ///     }
+    /// }
+    ///
+    /// let noon = WallClock::from(12 * 60);
+    /// assert_eq!(format!("{}", noon.display_with(TimeZone(0))), "12:00");
+    /// assert_eq!(format!("{}", noon.display_with(TimeZone(13 * 60 + 30))), "01:30");
+    /// ```
+    pub fn display_with<Ctx>(&self, ctx: Ctx) -> DisplayWithProxy<'_, Self, Ctx, Unit>
+    where
+        Unit: DisplayerWith<Self, Ctx>,
+    {
+        DisplayWithProxy::new(self, ctx)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<Unit, Repr> Instant<{ trait_flag::TRAIT_FLAGS_NO_COPY_IS_DEFAULT }, Unit, Repr> {
+    /// Converts to the `Copy` variant, keeping whether it's `Default`.
+    #[must_use]
+    pub fn into_copy(self) -> Instant<{ trait_flag::TRAIT_FLAGS_IS_COPY_IS_DEFAULT }, Unit, Repr> {
+        self.with_flags()
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<Unit, Repr> Instant<{ trait_flag::TRAIT_FLAGS_NO_COPY_NO_DEFAULT }, Unit, Repr> {
+    /// Converts to the `Copy` variant, keeping whether it's `Default`.
+    #[must_use]
+    pub fn into_copy(self) -> Instant<{ trait_flag::TRAIT_FLAGS_IS_COPY_NO_DEFAULT }, Unit, Repr> {
+        self.with_flags()
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<Unit, Repr> Instant<{ trait_flag::TRAIT_FLAGS_IS_COPY_IS_DEFAULT }, Unit, Repr> {
+    /// Converts to the non-`Copy` variant, keeping whether it's `Default`.
+    #[must_use]
+    pub fn into_no_copy(
+        self,
+    ) -> Instant<{ trait_flag::TRAIT_FLAGS_NO_COPY_IS_DEFAULT }, Unit, Repr> {
+        self.with_flags()
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<Unit, Repr> Instant<{ trait_flag::TRAIT_FLAGS_IS_COPY_NO_DEFAULT }, Unit, Repr> {
+    /// Converts to the non-`Copy` variant, keeping whether it's `Default`.
+    #[must_use]
+    pub fn into_no_copy(
+        self,
+    ) -> Instant<{ trait_flag::TRAIT_FLAGS_NO_COPY_NO_DEFAULT }, Unit, Repr> {
+        self.with_flags()
+    }
+}
+
+// These are deliberately named functions, not `From` impls: Instants (points) and amounts
+// (offsets) are kept apart everywhere else, so crossing over has to be explicit (and easy to
+// grep for), never an accidental `.into()`.
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Instant<TF, Unit, Repr> {
+    /// Reinterprets an amount (an offset) as an instant (a point in
+    /// time), that is, as the offset from the zero instant.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::{Amount, Instant};
+    ///
+    /// enum Millis {}
+    ///
+    /// let timeout = Amount::<Millis, u64>::from(500);
+    /// let deadline = Instant::from_amount(timeout);
+    /// assert_eq!(deadline, Instant::<Millis, u64>::from(500));
+    /// ```
+    pub fn from_amount(amount: Amount<TF, Unit, Repr>) -> Self {
+        Self::new(amount.into_inner())
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: PrimitiveInteger> Instant<TF, Unit, Repr> {
+    /// The zero instant, which other instants (of an integer `Repr`) are
+    /// counted from.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::{Amount, Instant};
+    ///
+    /// enum Tick {}
+    /// type Clock = Instant<Tick, u64>;
+    ///
+    /// const START: Clock = Clock::EPOCH;
+    /// assert_eq!(START, Clock::from(0));
+    /// assert_eq!(Clock::from(42).since_epoch(), Amount::from(42));
+    /// ```
+    pub const EPOCH: Self = Self::new(Repr::ZERO);
+
+    /// The offset from [Instant::EPOCH], as an amount. The inverse of
+    /// [Instant::from_amount].
+    #[must_use]
+    pub fn since_epoch(&self) -> Amount<TF, Unit, Repr> {
+        Amount::new(self.0)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Instant<TF, Unit, Repr>
+where
+    Repr: Sub<Output = Repr> + Copy,
+{
+    /// Returns the amount of units elapsed from `earlier` to `self`.
+    /// The same as `self - earlier`.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::{Amount, Instant};
+    ///
+    /// enum Ticks {}
+    ///
+    /// let start = Instant::<Ticks, u64>::from(3);
+    /// let end = Instant::<Ticks, u64>::from(10);
+    /// assert_eq!(end.duration_since(start), Amount::<Ticks, u64>::from(7));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn duration_since(&self, earlier: Self) -> Amount<TF, Unit, Repr> {
+        Amount::new(self.0 - earlier.0)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Instant<TF, Unit, Repr>
+where
+    Repr: SaturatingOps + Ord + Default,
+{
+    /// Returns the amount of units elapsed from `earlier` to `self`,
+    /// or zero if `earlier` is later than `self`. It never panics.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::{Amount, Instant};
+    ///
+    /// enum Ticks {}
+    ///
+    /// let start = Instant::<Ticks, u64>::from(3);
+    /// let end = Instant::<Ticks, u64>::from(10);
+    /// assert_eq!(end.saturating_duration_since(start), Amount::<Ticks, u64>::from(7));
+    /// assert_eq!(start.saturating_duration_since(end), Amount::<Ticks, u64>::from(0));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn saturating_duration_since(&self, earlier: Self) -> Amount<TF, Unit, Repr> {
+        if earlier.0 > self.0 {
+            Amount::new(Repr::default())
+        } else {
+            Amount::new(self.0.saturating_sub(earlier.0))
+        }
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: WrappingOps> Instant<TF, Unit, Repr> {
+    /// Returns the amount of units elapsed from `earlier` to `self` on
+    /// a clock that wraps around (like a fixed-width hardware timer),
+    /// subtracting modulo the range of `Repr`. That's correct across one
+    /// rollover, as long as less than a full period has elapsed.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::{Amount, Instant};
+    ///
+    /// enum Timer {}
+    ///
+    /// let before = Instant::<Timer, u16>::from(65_530);
+    /// let after = Instant::<Timer, u16>::from(4);
+    /// assert_eq!(after.wrapping_duration_since(before), Amount::<Timer, u16>::from(10));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn wrapping_duration_since(&self, earlier: Self) -> Amount<TF, Unit, Repr> {
+        Amount::new(self.0.wrapping_sub(earlier.0))
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: StepOps> Instant<TF, Unit, Repr> {
+    /// The instant one unit (tick) later. It saturates: The successor
+    /// of the latest representable instant is that instant itself (so
+    /// a logical clock never jumps back).
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Instant;
+    ///
+    /// enum Tick {}
+    /// type Clock = Instant<Tick, u8>;
+    ///
+    /// assert_eq!(Clock::from(7).next(), Clock::from(8));
+    /// assert_eq!(Clock::from(7).prev(), Clock::from(6));
+    /// assert_eq!(Clock::from(u8::MAX).next(), Clock::from(u8::MAX));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn next(&self) -> Self {
+        Self::new(self.0.saturating_next())
+    }
+
+    /// The instant one unit (tick) earlier. It saturates: The
+    /// predecessor of the earliest representable instant is that
+    /// instant itself.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn prev(&self) -> Self {
+        Self::new(self.0.saturating_prev())
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: Ord> Instant<TF, Unit, Repr> {
+    /// Restricts the instant to `lo..=hi`, like [Ord::clamp], but
+    /// without having to import [Ord].
+    ///
+    /// # Panics
+    ///
+    /// If `lo > hi`.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Instant;
+    ///
+    /// enum Frame {}
+    /// type FrameNo = Instant<Frame, u32>;
+    ///
+    /// let (first, last) = (FrameNo::from(10), FrameNo::from(20));
+    /// assert_eq!(FrameNo::from(3).clamp(first, last), first);
+    /// assert_eq!(FrameNo::from(3).max(first), first);
+    /// assert_eq!(FrameNo::from(3).min(first), FrameNo::from(3));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn clamp(self, lo: Self, hi: Self) -> Self {
+        Ord::clamp(self, lo, hi)
+    }
+
+    /// The earlier of the two instants, like [Ord::min].
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+
+    /// The later of the two instants, like [Ord::max].
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+
+    /// Wraps in [Reverse], so that earlier instants compare as greater.
+    /// Use it to turn `BinaryHeap` (a max-heap) into a min-heap,
+    /// popping the earliest instant first:
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::Instant;
+    /// use std::collections::BinaryHeap;
+    ///
+    /// enum Deadline {}
+    /// type DeadlineAt = Instant<Deadline, u64>;
+    ///
+    /// let mut deadlines = BinaryHeap::new();
+    /// deadlines.push(DeadlineAt::from(30).reversed());
+    /// deadlines.push(DeadlineAt::from(10).reversed());
+    /// deadlines.push(DeadlineAt::from(20).reversed());
+    ///
+    /// assert_eq!(deadlines.pop().map(|earliest| earliest.0), Some(DeadlineAt::from(10)));
+    /// assert_eq!(deadlines.pop().map(|earliest| earliest.0), Some(DeadlineAt::from(20)));
+    /// ```
+    pub fn reversed(self) -> Reverse<Self> {
+        Reverse(self)
+    }
+
+    /// The later of the two instants, like `max`. For merging logical
+    /// clocks, where it reads better.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use core::cmp::Ordering;
+    /// use phantom_newtype::Instant;
+    ///
+    /// enum Tick {}
+    /// type Clock = Instant<Tick, u64>;
+    ///
+    /// let (local, remote) = (Clock::from(5), Clock::from(8));
+    /// assert_eq!(local.later(remote), remote);
+    /// assert_eq!(local.earlier(remote), local);
+    /// assert_eq!(local.cmp_ticks(&remote), Ordering::Less);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn later(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+
+    /// The earlier of the two instants, like `min`.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn earlier(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+
+    /// Compares the two instants, like [Ord::cmp].
+    #[must_use]
+    pub fn cmp_ticks(&self, other: &Self) -> Ordering {
+        Ord::cmp(self, other)
+    }
 }
 
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
@@ -279,6 +882,13 @@ where
     }
 }
 
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> AsRef<Repr> for Instant<TF, Unit, Repr> {
+    fn as_ref(&self) -> &Repr {
+        &self.0
+    }
+}
+
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
 impl<const TF: TraitFlags, Unit, Repr> From<Repr> for Instant<TF, Unit, Repr> {
     fn from(repr: Repr) -> Self {
@@ -390,6 +1000,25 @@ where
     }
 }
 
+/// Subtracting an instant from another instant gives the amount of
+/// units between them, just like subtracting
+/// [`std::time::Instant`](https://doc.rust-lang.org/std/time/struct.Instant.html)s
+/// gives a `Duration`:
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::{Amount, Instant};
+///
+/// enum Epoch {}
+///
+/// let t1 = Instant::<Epoch, u64>::from(3);
+/// let t2 = Instant::<Epoch, u64>::from(10);
+/// assert_eq!(t2 - t1, Amount::<Epoch, u64>::from(7));
+/// ```
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
 impl<const TF: TraitFlags, Unit, Repr> Sub for Instant<TF, Unit, Repr>
 where
@@ -472,6 +1101,34 @@ where
     }
 }
 
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: fmt::LowerHex> fmt::LowerHex for Instant<TF, Unit, Repr> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: fmt::UpperHex> fmt::UpperHex for Instant<TF, Unit, Repr> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: fmt::Octal> fmt::Octal for Instant<TF, Unit, Repr> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Octal::fmt(&self.0, f)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr: fmt::Binary> fmt::Binary for Instant<TF, Unit, Repr> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(&self.0, f)
+    }
+}
+
 #[cfg(feature = "serde")]
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
 impl<const TF: TraitFlags, Unit, Repr: Serialize> Serialize for Instant<TF, Unit, Repr> {
@@ -491,6 +1148,59 @@ where
     }
 }
 
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<'a, const TF: TraitFlags, Unit: 'a, Repr> arbitrary::Arbitrary<'a> for Instant<TF, Unit, Repr>
+where
+    Repr: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Repr::arbitrary(u).map(Self::new)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Repr::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit: 'static, Repr> quickcheck::Arbitrary for Instant<TF, Unit, Repr>
+where
+    Repr: quickcheck::Arbitrary,
+{
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Self::new(Repr::arbitrary(g))
+    }
+
+    fn shrink(&self) -> alloc::boxed::Box<dyn Iterator<Item = Self>> {
+        alloc::boxed::Box::new(self.0.shrink().map(Self::new))
+    }
+}
+
+// Like with `serde`, the encoded forms of `Instant` and `Repr` are identical.
+#[cfg(feature = "borsh")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> borsh::BorshSerialize for Instant<TF, Unit, Repr>
+where
+    Repr: borsh::BorshSerialize,
+{
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> borsh::BorshDeserialize for Instant<TF, Unit, Repr>
+where
+    Repr: borsh::BorshDeserialize,
+{
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        Repr::deserialize_reader(reader).map(Self::new)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -498,18 +1208,139 @@ mod tests {
     #[test]
     fn test_complex_instant_arithmetics() {
         enum Seconds {}
-        enum UTC {}
+        enum Utc {}
 
         type Timestamp = Instant<Seconds, i64>;
         type TsDiff = Amount<Seconds, i64>;
-        type Date = Instant<UTC, Timestamp>;
+        type Date = Instant<Utc, Timestamp>;
 
         let epoch = Date::new(Timestamp::new(0));
         let date = Date::new(Timestamp::new(123456789));
-        let span = Amount::<UTC, TsDiff>::new(TsDiff::from(123456789));
+        let span = Amount::<Utc, TsDiff>::new(TsDiff::from(123456789));
 
         assert_eq!(date - epoch, span);
         assert_eq!(date - span, epoch);
         assert_eq!(epoch + span, date);
     }
+
+    #[test]
+    fn test_saturating_duration_since() {
+        enum Ticks {}
+        type Tick = Instant<Ticks, u8>;
+        type Elapsed = Amount<Ticks, u8>;
+
+        assert_eq!(
+            Tick::from(5).saturating_duration_since(Tick::from(6)),
+            Elapsed::from(0)
+        );
+        assert_eq!(
+            Tick::from(0).saturating_duration_since(Tick::from(u8::MAX)),
+            Elapsed::from(0)
+        );
+        assert_eq!(
+            Tick::from(u8::MAX).saturating_duration_since(Tick::from(0)),
+            Elapsed::from(u8::MAX)
+        );
+        assert_eq!(
+            Tick::from(5).saturating_duration_since(Tick::from(5)),
+            Elapsed::from(0)
+        );
+
+        type SignedTick = Instant<Ticks, i8>;
+        type SignedElapsed = Amount<Ticks, i8>;
+
+        assert_eq!(
+            SignedTick::from(-5).saturating_duration_since(SignedTick::from(5)),
+            SignedElapsed::from(0)
+        );
+        assert_eq!(
+            SignedTick::from(5).saturating_duration_since(SignedTick::from(-5)),
+            SignedElapsed::from(10)
+        );
+        assert_eq!(
+            SignedTick::from(i8::MAX).saturating_duration_since(SignedTick::from(i8::MIN)),
+            SignedElapsed::from(i8::MAX)
+        );
+    }
+
+    #[test]
+    fn test_next_prev() {
+        enum Tick {}
+        type Clock = InstantNoCopy<Tick, i64>;
+
+        assert_eq!(Clock::from(0).next(), Clock::from(1));
+        assert_eq!(Clock::from(0).prev(), Clock::from(-1));
+        assert_eq!(Clock::from(i64::MAX).next(), Clock::from(i64::MAX));
+        assert_eq!(Clock::from(i64::MAX).prev(), Clock::from(i64::MAX - 1));
+        assert_eq!(Clock::from(i64::MIN).prev(), Clock::from(i64::MIN));
+        assert_eq!(Clock::from(i64::MIN).next().prev(), Clock::from(i64::MIN));
+    }
+
+    #[test]
+    fn test_merge_clocks() {
+        use core::cmp::Ordering;
+
+        enum Tick {}
+        type Clock = Instant<Tick, u64>;
+
+        let local = [Clock::from(3), Clock::from(7), Clock::from(0)];
+        let remote = [Clock::from(5), Clock::from(2), Clock::from(0)];
+
+        let mut merged = local;
+        for (mine, theirs) in merged.iter_mut().zip(remote.iter()) {
+            *mine = mine.later(*theirs);
+        }
+        assert_eq!(merged, [Clock::from(5), Clock::from(7), Clock::from(0)]);
+
+        let orderings = [
+            local[0].cmp_ticks(&remote[0]),
+            local[1].cmp_ticks(&remote[1]),
+            local[2].cmp_ticks(&remote[2]),
+        ];
+        assert_eq!(
+            orderings,
+            [Ordering::Less, Ordering::Greater, Ordering::Equal]
+        );
+        assert_eq!(local[1].earlier(remote[1]), Clock::from(2));
+    }
+
+    #[test]
+    fn test_since_epoch() {
+        enum Tick {}
+        type Clock = Instant<Tick, i64>;
+
+        assert_eq!(Clock::EPOCH, Clock::from(0));
+        assert_eq!(Clock::EPOCH.since_epoch(), Amount::from(0));
+        assert_eq!(Clock::from(1_000).since_epoch(), Amount::from(1_000));
+        assert_eq!(Clock::from(-5).since_epoch(), Amount::from(-5));
+
+        let later = Clock::EPOCH + Amount::from(250);
+        assert_eq!(later.since_epoch(), later - Clock::EPOCH);
+        assert_eq!(Clock::from_amount(later.since_epoch()), later);
+    }
+
+    #[test]
+    fn test_wrapping_duration_since() {
+        enum Timer {}
+        type Tick = Instant<Timer, u16>;
+        type Elapsed = Amount<Timer, u16>;
+
+        assert_eq!(
+            Tick::from(500).wrapping_duration_since(Tick::from(100)),
+            Elapsed::from(400)
+        );
+        // Across the rollover.
+        assert_eq!(
+            Tick::from(0).wrapping_duration_since(Tick::from(u16::MAX)),
+            Elapsed::from(1)
+        );
+        assert_eq!(
+            Tick::from(99).wrapping_duration_since(Tick::from(65_000)),
+            Elapsed::from(635)
+        );
+        assert_eq!(
+            Tick::from(7).wrapping_duration_since(Tick::from(7)),
+            Elapsed::from(0)
+        );
+    }
 }
@@ -31,27 +31,68 @@
 //#![feature(unsized_const_params)] // https://github.com/rust-lang/rust/issues/95174
 
 mod amount;
+#[cfg(target_pointer_width = "64")]
+mod atomic;
 mod displayer;
+mod duration;
+mod fixed_scale;
 mod id;
 mod instant;
+pub mod ops;
 pub mod prelude;
 pub mod prelude_full;
+#[cfg(feature = "serde")]
+pub mod serde_array;
+#[cfg(feature = "serde")]
+pub mod serde_bounded;
+#[cfg(feature = "serde")]
+pub mod serde_map;
+#[cfg(feature = "serde")]
+pub mod serde_newtype;
+#[cfg(feature = "serde")]
+pub mod serde_or_zero;
+#[cfg(feature = "serde")]
+pub mod serde_str;
+mod stats;
+#[cfg(feature = "chrono")]
+mod time_scale;
 mod to;
+mod type_name;
+mod unit_convert;
+mod validate;
 
-#[cfg(not(feature = "unstable_generic_const_own_type"))]
-mod trait_flag;
-#[cfg(feature = "unstable_generic_const_own_type")]
+// The flags' encoding is unstable, so it's hidden from the stable API (see the module docs).
+#[cfg_attr(not(feature = "unstable_generic_const_own_type"), doc(hidden))]
 pub mod trait_flag;
 
-//#[cfg(feature = "alloc")]
-//extern crate alloc;
+#[cfg(any(feature = "alloc", feature = "quickcheck"))]
+extern crate alloc;
+
+#[cfg(target_pointer_width = "64")]
+pub use atomic::AtomicAmount;
+pub use displayer::{
+    DisplayProxy, DisplayWithProxy, DisplayerOf, DisplayerWith, UnitLabel, UseReprDisplay,
+};
+pub use duration::DurationUnit;
+pub use fixed_scale::FixedScale;
+pub use id::FromHexError;
+pub use stats::AmountStats;
+#[cfg(feature = "chrono")]
+pub use time_scale::TimeScale;
+pub use unit_convert::UnitConvert;
+pub use validate::{OutOfRange, Validate};
 
-pub use displayer::{DisplayProxy, DisplayerOf};
+#[cfg(feature = "proptest")]
+pub use amount::amount_in_range;
+pub use amount::{max_by_repr, min_by_repr};
 
 #[cfg(feature = "unstable_generic_const_own_type")]
 pub use id::Id as IdForFlags;
 
-pub use to::{As, AsFrom, AsFromMut, AsMut, To, ToFrom, ToFromMut, ToMut};
+pub use to::{
+    map_to, As, AsFrom, AsFromMut, AsMut, To, ToFrom, ToFromMut, ToMut, ToSlice, ToSliceMut, TryAs,
+    TryAsFrom, TryTo, TryToFrom,
+};
 
 // Short names. Also in mod prelude:
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
@@ -112,3 +153,145 @@ pub type InstantNoCopyNoDefault<Unit, Repr> =
 pub type InstantIsCopyIsDefault<Unit, Repr> = Instant<Unit, Repr>;
 pub type InstantIsCopyNoDefault<Unit, Repr> = InstantNoDefault<Unit, Repr>;
 pub type InstantNoCopyIsDefault<Unit, Repr> = InstantNoCopy<Unit, Repr>;
+
+/// Defines a unit (marker) type, and (optionally) type aliases of [Amount], [Id] and [Instant]
+/// over it with the given `Repr`.
+///
+/// The marker is a unit struct, with any attributes (like `#[derive(...)]`) passed before it. The
+/// aliases are named explicitly (`macro_rules!` can't concatenate identifiers), each of `Amount`,
+/// `Id` and `Instant` at most once. They have the same visibility as the marker. A `Repr` is given
+/// only together with at least one alias; the marker alone is `define_unit!(pub Meters)`.
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// phantom_newtype::define_unit!(
+///     #[derive(Debug, Default)]
+///     pub Meters => u64; Amount = Distance, Instant = Position
+/// );
+///
+/// let start = Position::from(10);
+/// let end = start + Distance::from(5);
+/// assert_eq!(end - start, Distance::from(5));
+/// assert_eq!(format!("{:?}", end.unit()), "Meters");
+/// ```
+///
+/// A `Repr` without any alias would be unused, so it's rejected:
+///
+/// ```compile_fail
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// phantom_newtype::define_unit!(pub Meters => u64);
+/// ```
+#[macro_export]
+macro_rules! define_unit {
+    ($(#[$meta:meta])* $vis:vis $unit:ident) => {
+        $(#[$meta])*
+        $vis struct $unit;
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis $unit:ident => $repr:ty;
+        $($kind:ident = $alias:ident),+ $(,)?
+    ) => {
+        $crate::define_unit!($(#[$meta])* $vis $unit);
+
+        $(
+            $crate::define_unit!(@alias $kind, $vis $alias, $unit, $repr);
+        )+
+    };
+    (@alias Amount, $vis:vis $alias:ident, $unit:ident, $repr:ty) => {
+        $vis type $alias = $crate::Amount<$unit, $repr>;
+    };
+    (@alias Id, $vis:vis $alias:ident, $unit:ident, $repr:ty) => {
+        $vis type $alias = $crate::Id<$unit, $repr>;
+    };
+    (@alias Instant, $vis:vis $alias:ident, $unit:ident, $repr:ty) => {
+        $vis type $alias = $crate::Instant<$unit, $repr>;
+    };
+}
+
+/// Implements [Default], [Debug](core::fmt::Debug) and [UnitLabel] for an existing unit (marker)
+/// struct, which must be a unit struct (like `struct Meters;`). `Debug` shows the struct name.
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::Amount;
+///
+/// pub struct Meters;
+/// phantom_newtype::impl_unit!(Meters, "m");
+///
+/// let distance = Amount::<Meters, u32>::from(5);
+/// assert_eq!(format!("{}", distance.display_labeled()), "5 m");
+/// assert_eq!(format!("{:?}", distance.unit()), "Meters");
+/// ```
+#[macro_export]
+macro_rules! impl_unit {
+    ($unit:ident, $label:expr $(,)?) => {
+        impl ::core::default::Default for $unit {
+            fn default() -> Self {
+                $unit
+            }
+        }
+
+        impl ::core::fmt::Debug for $unit {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(stringify!($unit))
+            }
+        }
+
+        impl $crate::UnitLabel for $unit {
+            const LABEL: &'static str = $label;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    mod units {
+        crate::define_unit!(pub Bytes => usize; Amount = Size, Id = BlobId, Instant = Offset,);
+        crate::define_unit!(#[derive(Clone, Copy, Debug, Default, PartialEq)] pub(crate) Pages);
+
+        pub struct Seconds;
+        crate::impl_unit!(Seconds, "s");
+        crate::define_unit!(pub Liters => u64; Amount = Volume);
+        crate::impl_unit!(Liters, "l",);
+    }
+    use units::*;
+
+    #[test]
+    fn test_define_unit() {
+        let size: crate::Amount<Bytes, usize> = Size::from(16);
+        let id: crate::Id<Bytes, usize> = BlobId::from(1);
+        let offset: crate::Instant<Bytes, usize> = Offset::from(32) + size;
+        assert_eq!(offset, Offset::from(48));
+        assert_eq!(*id.get(), 1);
+
+        assert_eq!(crate::Amount::<Pages, u32>::from(3).unit(), Pages);
+    }
+
+    #[test]
+    fn test_impl_unit() {
+        extern crate std;
+        use std::format;
+
+        assert_eq!(<Seconds as crate::UnitLabel>::LABEL, "s");
+        let elapsed = crate::Amount::<Seconds, u32>::from(90);
+        // `unit()` needs `Default`.
+        assert_eq!(format!("{:?}", elapsed.unit()), "Seconds");
+        assert_eq!(format!("{}", elapsed.display_labeled()), "90 s");
+
+        assert_eq!(format!("{}", Volume::from(3).display_labeled()), "3 l");
+        assert_eq!(format!("{:?}", Volume::from(3).unit()), "Liters");
+    }
+}
@@ -0,0 +1,564 @@
+// Copyright 2024 Peter Lyons Kehl
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helper traits exposing operations of primitive integers (and, for some, floats), so that they
+//! can be used on a generic `Repr`.
+//!
+//! These traits are sealed: They are implemented for the primitive numeric types only, and they
+//! can't be implemented outside of this crate.
+
+use core::convert::TryFrom;
+use core::num::TryFromIntError;
+#[cfg(target_pointer_width = "64")]
+use core::sync::atomic::{self, Ordering};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Implemented for (exactly) the primitive integer types. Used as a bound where no operation is
+/// needed, but the `Repr` has to be an integer.
+pub trait PrimitiveInteger: Copy + private::Sealed {
+    const ZERO: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    /// Calls the `deserialize_*` method of `deserializer` for `Self` (like `deserialize_u8` for
+    /// `u8`), passing `visitor` to it.
+    #[cfg(feature = "serde")]
+    fn deserialize_primitive<'de, D, V>(deserializer: D, visitor: V) -> Result<V::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+        V: serde::de::Visitor<'de>;
+}
+
+/// Operations of primitive integers that return [None] on overflow or division by zero (rather
+/// than panicking or wrapping).
+pub trait CheckedOps: Copy + private::Sealed {
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+}
+
+/// Operations of primitive integers that clamp at the numeric bounds on overflow.
+pub trait SaturatingOps: Copy + private::Sealed {
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_sub(self, rhs: Self) -> Self;
+}
+
+/// Operations of primitive integers that wrap around at the numeric bounds (two's complement).
+///
+/// Trait methods can't be `const fn` in stable Rust, hence neither can be methods that use them.
+pub trait WrappingOps: Copy + private::Sealed {
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    fn wrapping_mul(self, rhs: Self) -> Self;
+}
+
+/// The distance between two primitive integers, which never overflows. For a signed type, the
+/// distance is of the unsigned type of the same width.
+pub trait AbsDiffOps: Copy + private::Sealed {
+    type Output;
+    fn abs_diff(self, other: Self) -> Self::Output;
+}
+
+/// The average of two primitive integers, computed without intermediate overflow. Like the
+/// primitive `midpoint` (which needs Rust 1.85, or 1.87 for signed types), it rounds down for
+/// unsigned types, and towards zero for signed types.
+pub trait MidpointOps: Copy + private::Sealed {
+    fn midpoint(self, rhs: Self) -> Self;
+}
+
+/// Stepping a primitive integer by one, saturating at the numeric bounds.
+pub trait StepOps: Copy + private::Sealed {
+    fn saturating_next(self) -> Self;
+    fn saturating_prev(self) -> Self;
+}
+
+/// Integer division of primitive integers, rounding towards negative infinity (floor) or positive
+/// infinity (ceiling) rather than towards zero (as `/` does). They differ from `/` only for signed
+/// types with operands of opposite signs. Panic on division by zero, like `/`.
+pub trait DivOps: Copy + private::Sealed {
+    fn div_floor(self, rhs: Self) -> Self;
+    fn div_ceil(self, rhs: Self) -> Self;
+    /// The floored quotient, and the matching remainder (which has the sign of `rhs`, or is zero).
+    fn div_rem_floor(self, rhs: Self) -> (Self, Self);
+}
+
+/// Sign-related operations of signed primitive integers (not implemented for unsigned ones).
+pub trait SignedOps: Copy + private::Sealed {
+    fn signum(self) -> Self;
+    fn is_negative(self) -> bool;
+    fn is_positive(self) -> bool;
+}
+
+/// Absolute value of signed primitive integers and of floats (not implemented for unsigned
+/// integers). For integers, like the primitive `abs`, the absolute value of `MIN` overflows: This
+/// panics in debug builds, and returns `MIN` in release builds.
+pub trait AbsOps: Copy + private::Sealed {
+    fn abs(self) -> Self;
+}
+
+/// Conversions of primitive integers from and to their memory representation, as byte arrays.
+pub trait ByteOps: Copy + private::Sealed {
+    /// The byte array, `[u8; Self::BYTES]`.
+    type Bytes;
+    /// The size in bytes.
+    const BYTES: usize;
+    fn to_le_bytes(self) -> Self::Bytes;
+    fn to_be_bytes(self) -> Self::Bytes;
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+}
+
+/// Checked conversions of primitive integers from and to `usize` (for indexing). Whether they can
+/// fail depends on the type and on the target's pointer width, but they all report failure the
+/// same way.
+pub trait UsizeOps: Copy + private::Sealed {
+    fn try_into_usize(self) -> Result<usize, TryFromIntError>;
+    fn try_from_usize(n: usize) -> Result<Self, TryFromIntError>;
+}
+
+/// Scaling of primitive integers by a ratio, through an intermediate of twice the width (like
+/// `u128` for `u64`), so that the product doesn't overflow. Not implemented for 128-bit types,
+/// which have no wider primitive.
+pub trait ScaleOps: Copy + private::Sealed {
+    /// `self * num / den`, rounded towards zero.
+    ///
+    /// # Panics
+    ///
+    /// If `den` is zero, or if the result doesn't fit `Self`.
+    fn scale_by(self, num: Self, den: Self) -> Self;
+}
+
+/// Greatest common divisor and least common multiple of primitive integers. For signed types, both
+/// are non-negative (they are those of the absolute values).
+pub trait GcdOps: Copy + private::Sealed {
+    /// Euclidean algorithm. `gcd(x, 0)` is the absolute value of `x`.
+    ///
+    /// For signed types, `gcd(MIN, 0)` and `gcd(MIN, MIN)` are `-MIN`, which overflows: Like
+    /// `abs` of `MIN`, this panics in debug builds, and returns `MIN` in release builds.
+    fn gcd(self, other: Self) -> Self;
+    /// Divides by the gcd before multiplying, so it overflows only if the result itself doesn't
+    /// fit `Self` (which panics in debug builds, and wraps in release builds). `lcm(x, 0)` is zero.
+    fn lcm(self, other: Self) -> Self;
+}
+
+macro_rules! impl_for_integers {
+    ($($t:ty: $deserialize:ident)*) => {$(
+        impl private::Sealed for $t {}
+
+        impl PrimitiveInteger for $t {
+            const ZERO: Self = 0;
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+
+            #[cfg(feature = "serde")]
+            fn deserialize_primitive<'de, D, V>(
+                deserializer: D,
+                visitor: V,
+            ) -> Result<V::Value, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+                V: serde::de::Visitor<'de>,
+            {
+                deserializer.$deserialize(visitor)
+            }
+        }
+
+        impl CheckedOps for $t {
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_div(self, rhs)
+            }
+            fn checked_rem(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_rem(self, rhs)
+            }
+        }
+
+        impl SaturatingOps for $t {
+            fn saturating_add(self, rhs: Self) -> Self {
+                <$t>::saturating_add(self, rhs)
+            }
+            fn saturating_sub(self, rhs: Self) -> Self {
+                <$t>::saturating_sub(self, rhs)
+            }
+        }
+
+        impl WrappingOps for $t {
+            fn wrapping_add(self, rhs: Self) -> Self {
+                <$t>::wrapping_add(self, rhs)
+            }
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                <$t>::wrapping_sub(self, rhs)
+            }
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                <$t>::wrapping_mul(self, rhs)
+            }
+        }
+
+        impl StepOps for $t {
+            fn saturating_next(self) -> Self {
+                <$t>::saturating_add(self, 1)
+            }
+            fn saturating_prev(self) -> Self {
+                <$t>::saturating_sub(self, 1)
+            }
+        }
+
+        // The error is `Infallible` where the conversion can't fail, hence `Into`.
+        impl UsizeOps for $t {
+            fn try_into_usize(self) -> Result<usize, TryFromIntError> {
+                usize::try_from(self).map_err(Into::into)
+            }
+            fn try_from_usize(n: usize) -> Result<Self, TryFromIntError> {
+                <$t>::try_from(n).map_err(Into::into)
+            }
+        }
+
+        impl ByteOps for $t {
+            type Bytes = [u8; core::mem::size_of::<$t>()];
+            const BYTES: usize = core::mem::size_of::<$t>();
+            fn to_le_bytes(self) -> Self::Bytes {
+                <$t>::to_le_bytes(self)
+            }
+            fn to_be_bytes(self) -> Self::Bytes {
+                <$t>::to_be_bytes(self)
+            }
+            fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                <$t>::from_le_bytes(bytes)
+            }
+            fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                <$t>::from_be_bytes(bytes)
+            }
+        }
+    )*};
+}
+
+impl_for_integers! {
+    u8: deserialize_u8 u16: deserialize_u16 u32: deserialize_u32 u64: deserialize_u64
+    u128: deserialize_u128 usize: deserialize_u64
+    i8: deserialize_i8 i16: deserialize_i16 i32: deserialize_i32 i64: deserialize_i64
+    i128: deserialize_i128 isize: deserialize_i64
+}
+
+// The primitive `abs_diff` needs Rust 1.60.
+macro_rules! impl_abs_diff_unsigned {
+    ($($t:ty),*) => {$(
+        impl AbsDiffOps for $t {
+            type Output = $t;
+            fn abs_diff(self, other: Self) -> $t {
+                if self > other {
+                    self - other
+                } else {
+                    other - self
+                }
+            }
+        }
+    )*};
+}
+
+// The difference fits the unsigned type, even though it may not fit the signed one. Two's
+// complement subtraction of the reinterpreted bits yields it.
+macro_rules! impl_abs_diff_signed {
+    ($($t:ty => $out:ty),*) => {$(
+        impl AbsDiffOps for $t {
+            type Output = $out;
+            fn abs_diff(self, other: Self) -> $out {
+                if self > other {
+                    (self as $out).wrapping_sub(other as $out)
+                } else {
+                    (other as $out).wrapping_sub(self as $out)
+                }
+            }
+        }
+    )*};
+}
+
+impl_abs_diff_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_abs_diff_signed! {
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+}
+
+// The shared bits, plus half of the differing ones, is the floored average (the shift is
+// arithmetic for signed types).
+macro_rules! impl_midpoint_unsigned {
+    ($($t:ty),*) => {$(
+        impl MidpointOps for $t {
+            fn midpoint(self, rhs: Self) -> Self {
+                (self & rhs) + ((self ^ rhs) >> 1)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_midpoint_signed {
+    ($($t:ty),*) => {$(
+        impl MidpointOps for $t {
+            fn midpoint(self, rhs: Self) -> Self {
+                let floor = (self & rhs) + ((self ^ rhs) >> 1);
+                // An odd sum has a fractional half: Round a negative average up, towards zero.
+                if floor < 0 && (self ^ rhs) & 1 != 0 {
+                    floor + 1
+                } else {
+                    floor
+                }
+            }
+        }
+    )*};
+}
+
+impl_midpoint_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_midpoint_signed!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_div_ops_unsigned {
+    ($($t:ty),*) => {$(
+        impl DivOps for $t {
+            fn div_floor(self, rhs: Self) -> Self {
+                self / rhs
+            }
+            // The primitive `div_ceil` needs Rust 1.73.
+            fn div_ceil(self, rhs: Self) -> Self {
+                self / rhs + (self % rhs != 0) as $t
+            }
+            fn div_rem_floor(self, rhs: Self) -> (Self, Self) {
+                (self / rhs, self % rhs)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_div_ops_signed {
+    ($($t:ty),*) => {$(
+        impl DivOps for $t {
+            fn div_floor(self, rhs: Self) -> Self {
+                self.div_rem_floor(rhs).0
+            }
+            fn div_ceil(self, rhs: Self) -> Self {
+                let (quotient, remainder) = (self / rhs, self % rhs);
+                if remainder != 0 && (remainder > 0) == (rhs > 0) {
+                    quotient + 1
+                } else {
+                    quotient
+                }
+            }
+            fn div_rem_floor(self, rhs: Self) -> (Self, Self) {
+                let (quotient, remainder) = (self / rhs, self % rhs);
+                if remainder != 0 && (remainder < 0) != (rhs < 0) {
+                    (quotient - 1, remainder + rhs)
+                } else {
+                    (quotient, remainder)
+                }
+            }
+        }
+    )*};
+}
+
+impl_div_ops_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_div_ops_signed!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_signed_ops {
+    ($($t:ty),*) => {$(
+        impl SignedOps for $t {
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+            fn is_negative(self) -> bool {
+                <$t>::is_negative(self)
+            }
+            fn is_positive(self) -> bool {
+                <$t>::is_positive(self)
+            }
+        }
+    )*};
+}
+
+impl_signed_ops!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_abs_ops_integers {
+    ($($t:ty),*) => {$(
+        impl AbsOps for $t {
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+        }
+    )*};
+}
+
+impl_abs_ops_integers!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_scale_ops {
+    ($($t:ty => $wide:ty),*) => {$(
+        impl ScaleOps for $t {
+            fn scale_by(self, num: Self, den: Self) -> Self {
+                let scaled = <$wide>::from(self) * <$wide>::from(num) / <$wide>::from(den);
+                <$t>::try_from(scaled).expect("overflow when scaling an integer")
+            }
+        }
+    )*};
+}
+
+impl_scale_ops! {
+    u8 => u16, u16 => u32, u32 => u64, u64 => u128,
+    i8 => i16, i16 => i32, i32 => i64, i64 => i128
+}
+
+macro_rules! impl_gcd_ops {
+    ($($t:ty),*) => {$(
+        impl GcdOps for $t {
+            fn gcd(self, other: Self) -> Self {
+                let (mut a, mut b) = (self, other);
+                while b != 0 {
+                    let remainder = a % b;
+                    a = b;
+                    b = remainder;
+                }
+                a
+            }
+            fn lcm(self, other: Self) -> Self {
+                if self == 0 || other == 0 {
+                    0
+                } else {
+                    self / self.gcd(other) * other
+                }
+            }
+        }
+    )*};
+}
+
+// Through the unsigned type of the same width, which fits the absolute values (even of `MIN`).
+macro_rules! impl_gcd_ops_signed {
+    ($($t:ty => $unsigned:ty),*) => {$(
+        impl GcdOps for $t {
+            fn gcd(self, other: Self) -> Self {
+                let gcd = self.unsigned_abs().gcd(other.unsigned_abs());
+                debug_assert!(gcd <= <$t>::MAX as $unsigned, "overflow in gcd");
+                gcd as $t
+            }
+            fn lcm(self, other: Self) -> Self {
+                let lcm = self.unsigned_abs().lcm(other.unsigned_abs());
+                debug_assert!(lcm <= <$t>::MAX as $unsigned, "overflow in lcm");
+                lcm as $t
+            }
+        }
+    )*};
+}
+
+impl_gcd_ops!(u8, u16, u32, u64, u128, usize);
+impl_gcd_ops_signed! {
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+}
+
+// `usize` and `isize` have no `From` into fixed-width types (those are target-dependent), hence
+// casts. They are at most 64 bits wide on all supported targets.
+impl ScaleOps for usize {
+    fn scale_by(self, num: Self, den: Self) -> Self {
+        let scaled = (self as u64).scale_by(num as u64, den as u64);
+        usize::try_from(scaled).expect("overflow when scaling an integer")
+    }
+}
+
+impl ScaleOps for isize {
+    fn scale_by(self, num: Self, den: Self) -> Self {
+        let scaled = (self as i64).scale_by(num as i64, den as i64);
+        isize::try_from(scaled).expect("overflow when scaling an integer")
+    }
+}
+
+// The floats' `abs` is not in `core` on older Rust, hence clearing the sign bit (which is what it
+// does, for NaN too).
+macro_rules! impl_abs_ops_floats {
+    ($($t:ty: $sign_bit:expr),*) => {$(
+        impl private::Sealed for $t {}
+
+        impl AbsOps for $t {
+            fn abs(self) -> Self {
+                <$t>::from_bits(self.to_bits() & !$sign_bit)
+            }
+        }
+    )*};
+}
+
+impl_abs_ops_floats!(f32: 1_u32 << 31, f64: 1_u64 << 63);
+
+/// Maps a primitive integer to its atomic counterpart (like `u64` to
+/// [AtomicU64](core::sync::atomic::AtomicU64)), for [AtomicAmount](crate::AtomicAmount).
+///
+/// Only on 64-bit targets, which all have the atomics (with compare-and-swap) of up to 64 bits.
+/// (Checking for each atomic with `cfg(target_has_atomic)` needs Rust 1.60.)
+#[cfg(target_pointer_width = "64")]
+pub trait AtomicRepr: Copy + private::Sealed {
+    type Atomic;
+    fn new_atomic(value: Self) -> Self::Atomic;
+    fn load(atomic: &Self::Atomic, order: Ordering) -> Self;
+    fn store(atomic: &Self::Atomic, value: Self, order: Ordering);
+    fn fetch_add(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self;
+    fn compare_exchange(
+        atomic: &Self::Atomic,
+        current: Self,
+        new: Self,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self, Self>;
+    fn into_inner(atomic: Self::Atomic) -> Self;
+}
+
+#[cfg(target_pointer_width = "64")]
+macro_rules! impl_atomic_repr {
+    ($($t:ty => $atomic:ident),*) => {$(
+        impl AtomicRepr for $t {
+            type Atomic = atomic::$atomic;
+            fn new_atomic(value: Self) -> Self::Atomic {
+                atomic::$atomic::new(value)
+            }
+            fn load(atomic: &Self::Atomic, order: Ordering) -> Self {
+                atomic.load(order)
+            }
+            fn store(atomic: &Self::Atomic, value: Self, order: Ordering) {
+                atomic.store(value, order)
+            }
+            fn fetch_add(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self {
+                atomic.fetch_add(value, order)
+            }
+            fn compare_exchange(
+                atomic: &Self::Atomic,
+                current: Self,
+                new: Self,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<Self, Self> {
+                atomic.compare_exchange(current, new, success, failure)
+            }
+            fn into_inner(atomic: Self::Atomic) -> Self {
+                atomic.into_inner()
+            }
+        }
+    )*};
+}
+
+#[cfg(target_pointer_width = "64")]
+impl_atomic_repr! {
+    u8 => AtomicU8, u16 => AtomicU16, u32 => AtomicU32, u64 => AtomicU64, usize => AtomicUsize,
+    i8 => AtomicI8, i16 => AtomicI16, i32 => AtomicI32, i64 => AtomicI64, isize => AtomicIsize
+}
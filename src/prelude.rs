@@ -16,3 +16,15 @@ pub use crate::Instant;
 pub use crate::InstantNoCopy;
 pub use crate::InstantNoCopyNoDefault;
 pub use crate::InstantNoDefault;
+
+// Method-providing conversion traits, anonymously: Their methods are in scope, but their names
+// don't clash with the caller's (or core's, like `AsMut`). The marker traits (`As`, `AsMut`,
+// `TryAs`...) provide no methods; import them by name to implement them.
+pub use crate::To as _;
+pub use crate::ToFrom as _;
+pub use crate::ToFromMut as _;
+pub use crate::ToMut as _;
+pub use crate::ToSlice as _;
+pub use crate::ToSliceMut as _;
+pub use crate::TryTo as _;
+pub use crate::TryToFrom as _;
@@ -0,0 +1,280 @@
+// Copyright 2024 Peter Lyons Kehl
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! (De)serializes a fixed-size array `[Amount; N]` as a flat array of `Repr`, checking its length.
+//!
+//! The wire format is the same as with the default implementations. However, when the length
+//! doesn't match `N`, the error names both the expected length and the unit (like `invalid length
+//! 2, expected an array of 3 Coord amounts`), and it also rejects any extra elements.
+//!
+//! Opt in per field:
+//!
+//! ```
+//! #![cfg_attr(
+//!     feature = "unstable_generic_const_own_type",
+//!     feature(generic_const_exprs)
+//! )]
+//!
+//! #[cfg(feature = "serde")] {
+//! use phantom_newtype::Amount;
+//! use serde::{Deserialize, Serialize};
+//!
+//! enum Coord {}
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Point {
+//!     #[serde(with = "phantom_newtype::serde_array")]
+//!     xyz: [Amount<Coord, f64>; 3],
+//! }
+//!
+//! let point = Point { xyz: [Amount::from(1.5), Amount::from(0.0), Amount::from(-2.0)] };
+//! let json = serde_json::to_string(&point).unwrap();
+//! assert_eq!(json, r#"{"xyz":[1.5,0.0,-2.0]}"#);
+//! assert_eq!(serde_json::from_str::<Point>(&json).unwrap(), point);
+//!
+//! let error = serde_json::from_str::<Point>(r#"{"xyz":[1.5,0.0]}"#).unwrap_err();
+//! assert!(error.to_string().starts_with("invalid length 2, expected an array of 3 Coord amounts"));
+//! }
+//! ```
+use crate::amount::Amount;
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+use crate::trait_flag::TraitFlags;
+use crate::type_name::short_type_name;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ptr;
+use serde::de::{self, Deserialize, Deserializer, IgnoredAny, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+pub fn serialize<S, const TF: TraitFlags, Unit, Repr, const N: usize>(
+    amounts: &[Amount<TF, Unit, Repr>; N],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    Repr: Serialize,
+{
+    let mut tuple = serializer.serialize_tuple(N)?;
+    for amount in amounts {
+        tuple.serialize_element(amount.get_ref())?;
+    }
+    tuple.end()
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+pub fn deserialize<'de, D, const TF: TraitFlags, Unit, Repr, const N: usize>(
+    deserializer: D,
+) -> Result<[Amount<TF, Unit, Repr>; N], D::Error>
+where
+    D: Deserializer<'de>,
+    Repr: Deserialize<'de>,
+{
+    deserializer.deserialize_tuple(N, ArrayVisitor::<TF, Unit, Repr, N>(PhantomData))
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+struct ArrayVisitor<const TF: TraitFlags, Unit, Repr, const N: usize>(
+    PhantomData<fn() -> (Unit, Repr)>,
+);
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<'de, const TF: TraitFlags, Unit, Repr, const N: usize> Visitor<'de>
+    for ArrayVisitor<TF, Unit, Repr, N>
+where
+    Repr: Deserialize<'de>,
+{
+    type Value = [Amount<TF, Unit, Repr>; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "an array of {} {} amounts", N, short_type_name::<Unit>())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut array = PartialArray::<Amount<TF, Unit, Repr>, N>::new();
+        while array.len < N {
+            match seq.next_element()? {
+                Some(repr) => array.push(Amount::new(repr)),
+                None => return Err(de::Error::invalid_length(array.len, &self)),
+            }
+        }
+        // Report the full length, rather than failing at the first extra element.
+        let mut len = N;
+        while seq.next_element::<IgnoredAny>()?.is_some() {
+            len += 1;
+        }
+        if len > N {
+            return Err(de::Error::invalid_length(len, &self));
+        }
+        Ok(array.into_full())
+    }
+}
+
+/// An array being filled from the front. It drops the elements filled so far, if it's dropped
+/// before being full (on an error, or on a panic). `[T; N]::map`, which would make this simpler,
+/// needs Rust 1.55.
+struct PartialArray<T, const N: usize> {
+    items: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> PartialArray<T, N> {
+    fn new() -> Self {
+        Self {
+            // SAFETY: An array of `MaybeUninit` doesn't need initialization.
+            items: unsafe { MaybeUninit::uninit().assume_init() },
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, item: T) {
+        self.items[self.len] = MaybeUninit::new(item);
+        self.len += 1;
+    }
+
+    fn into_full(mut self) -> [T; N] {
+        assert_eq!(self.len, N);
+        // Moving the items out, so `drop` must not drop them.
+        self.len = 0;
+        // SAFETY: All `N` items are initialized, and `MaybeUninit<T>` has the same layout as `T`.
+        unsafe { ptr::read(self.items.as_ptr().cast::<[T; N]>()) }
+    }
+}
+
+impl<T, const N: usize> Drop for PartialArray<T, N> {
+    fn drop(&mut self) {
+        for item in &mut self.items[..self.len] {
+            // SAFETY: The first `len` items are initialized.
+            unsafe { ptr::drop_in_place(item.as_mut_ptr()) }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Amount, AmountNoCopy};
+    use serde::{Deserialize, Serialize};
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    enum Coord {}
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Point {
+        #[serde(with = "crate::serde_array")]
+        xyz: [Amount<Coord, i32>; 3],
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let point = Point {
+            xyz: [Amount::from(1), Amount::from(-2), Amount::from(3)],
+        };
+        assert_tokens(
+            &point,
+            &[
+                Token::Struct {
+                    name: "Point",
+                    len: 1,
+                },
+                Token::Str("xyz"),
+                Token::Tuple { len: 3 },
+                Token::I32(1),
+                Token::I32(-2),
+                Token::I32(3),
+                Token::TupleEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_length_mismatch() {
+        extern crate std;
+        use std::string::ToString;
+
+        assert_de_tokens_error::<Point>(
+            &[
+                Token::Struct {
+                    name: "Point",
+                    len: 1,
+                },
+                Token::Str("xyz"),
+                Token::Seq { len: Some(2) },
+                Token::I32(1),
+                Token::I32(2),
+                Token::SeqEnd,
+            ],
+            "invalid length 2, expected an array of 3 Coord amounts",
+        );
+
+        let too_long = serde_json::from_str::<Point>(r#"{"xyz":[1,2,3,4,5]}"#).unwrap_err();
+        assert!(too_long
+            .to_string()
+            .starts_with("invalid length 5, expected an array of 3 Coord amounts"));
+    }
+
+    #[test]
+    fn test_drop_partially_deserialized() {
+        extern crate std;
+        use core::cell::Cell;
+        use serde::Deserializer;
+        use std::string::String;
+
+        std::thread_local! {
+            // `const { ... }` initializers need Rust 1.59.
+            #[allow(clippy::missing_const_for_thread_local)]
+            static DROPS: Cell<usize> = Cell::new(0);
+        }
+
+        /// Deserialized from a string (anything else is invalid). It counts its drops, per thread.
+        #[derive(Debug)]
+        struct Counted;
+
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                DROPS.with(|drops| drops.set(drops.get() + 1));
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Counted {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer).map(|_| Counted)
+            }
+        }
+
+        #[derive(Deserialize, Debug)]
+        struct Names {
+            #[serde(with = "crate::serde_array")]
+            _names: [AmountNoCopy<Coord, Counted>; 2],
+        }
+
+        let drops_after = |json: &str| {
+            DROPS.with(|drops| drops.set(0));
+            assert!(serde_json::from_str::<Names>(json).is_err());
+            DROPS.with(Cell::get)
+        };
+        // The first element is dropped (once), when the second one is missing or invalid.
+        assert_eq!(drops_after(r#"{"_names":["a"]}"#), 1);
+        assert_eq!(drops_after(r#"{"_names":["a",1]}"#), 1);
+        // Both elements are dropped. Extra elements are ignored, rather than deserialized.
+        assert_eq!(drops_after(r#"{"_names":["a","b","c"]}"#), 2);
+
+        DROPS.with(|drops| drops.set(0));
+        let names = serde_json::from_str::<Names>(r#"{"_names":["a","b"]}"#).unwrap();
+        assert_eq!(DROPS.with(Cell::get), 0);
+        drop(names);
+        assert_eq!(DROPS.with(Cell::get), 2);
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright 2024 Peter Lyons Kehl
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! (De)serializes an [crate::Amount] with a primitive integer `Repr` just like the default
+//! `Serialize` and `Deserialize` implementations do, but when deserializing an out of range number,
+//! the error names the unit and the acceptable range. Handy for config files.
+//!
+//! Opt in per field:
+//!
+//! ```
+//! #![cfg_attr(
+//!     feature = "unstable_generic_const_own_type",
+//!     feature(generic_const_exprs)
+//! )]
+//!
+//! #[cfg(feature = "serde")] {
+//! use phantom_newtype::Amount;
+//! use serde::{Deserialize, Serialize};
+//!
+//! enum Retries {}
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Config {
+//!     #[serde(with = "phantom_newtype::serde_bounded")]
+//!     retries: Amount<Retries, u8>,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{"retries":3}"#).unwrap();
+//! assert_eq!(config.retries, 3);
+//!
+//! let err = serde_json::from_str::<Config>(r#"{"retries":300}"#).unwrap_err();
+//! assert!(err.to_string().starts_with(
+//!     "invalid value: integer `300`, expected an amount of Retries in 0..=255"
+//! ));
+//! }
+//! ```
+use crate::amount::Amount;
+use crate::ops::PrimitiveInteger;
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+use crate::trait_flag::TraitFlags;
+use crate::type_name::short_type_name;
+use core::convert::TryFrom;
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Serialize, Serializer};
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+pub fn serialize<S, const TF: TraitFlags, Unit, Repr>(
+    amount: &Amount<TF, Unit, Repr>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    Repr: Serialize,
+{
+    amount.get_ref().serialize(serializer)
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+pub fn deserialize<'de, D, const TF: TraitFlags, Unit, Repr>(
+    deserializer: D,
+) -> Result<Amount<TF, Unit, Repr>, D::Error>
+where
+    D: Deserializer<'de>,
+    Repr: PrimitiveInteger
+        + fmt::Display
+        + TryFrom<i64>
+        + TryFrom<u64>
+        + TryFrom<i128>
+        + TryFrom<u128>,
+{
+    Repr::deserialize_primitive(deserializer, BoundedVisitor::<TF, Unit, Repr>(PhantomData))
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+struct BoundedVisitor<const TF: TraitFlags, Unit, Repr>(PhantomData<fn() -> (Unit, Repr)>);
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> BoundedVisitor<TF, Unit, Repr>
+where
+    Self: for<'de> Visitor<'de>,
+{
+    fn bounded<E: de::Error, V>(&self, value: V) -> Result<Amount<TF, Unit, Repr>, E>
+    where
+        V: Copy + fmt::Display,
+        Repr: TryFrom<V>,
+    {
+        Repr::try_from(value).map(Amount::new).map_err(|_| {
+            E::custom(format_args!(
+                "invalid value: integer `{}`, expected {}",
+                value,
+                Expected(self)
+            ))
+        })
+    }
+}
+
+// `de::Expected` of a visitor is a `Display` only through `&dyn Expected`.
+struct Expected<'a, V>(&'a V);
+
+impl<'de, V: Visitor<'de>> fmt::Display for Expected<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.expecting(f)
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<'de, const TF: TraitFlags, Unit, Repr> Visitor<'de> for BoundedVisitor<TF, Unit, Repr>
+where
+    Repr: PrimitiveInteger
+        + fmt::Display
+        + TryFrom<i64>
+        + TryFrom<u64>
+        + TryFrom<i128>
+        + TryFrom<u128>,
+{
+    type Value = Amount<TF, Unit, Repr>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "an amount of {} in {}..={}",
+            short_type_name::<Unit>(),
+            Repr::MIN,
+            Repr::MAX
+        )
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        self.bounded(v)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        self.bounded(v)
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        self.bounded(v)
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        self.bounded(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use crate::Amount;
+    use serde::{Deserialize, Serialize};
+    use std::string::ToString;
+
+    enum Bytes {}
+    enum Celsius {}
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Config {
+        #[serde(with = "crate::serde_bounded")]
+        buffer: Amount<Bytes, u8>,
+        #[serde(with = "crate::serde_bounded")]
+        temperature: Amount<Celsius, i16>,
+    }
+
+    fn error(json: &str) -> std::string::String {
+        serde_json::from_str::<Config>(json)
+            .unwrap_err()
+            .to_string()
+    }
+
+    #[test]
+    fn test_in_range() {
+        let config = Config {
+            buffer: Amount::from(255),
+            temperature: Amount::from(-40),
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"buffer":255,"temperature":-40}"#);
+        assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+    }
+
+    #[test]
+    fn test_out_of_range_names_unit() {
+        let err = error(r#"{"buffer":300,"temperature":0}"#);
+        assert!(err.contains("Bytes"), "{}", err);
+        assert!(err.contains("0..=255"), "{}", err);
+        assert!(err.contains("`300`"), "{}", err);
+
+        let err = error(r#"{"buffer":0,"temperature":-40000}"#);
+        assert!(err.contains("Celsius"), "{}", err);
+        assert!(err.contains("-32768..=32767"), "{}", err);
+
+        let err = error(r#"{"buffer":-1,"temperature":0}"#);
+        assert!(err.contains("Bytes"), "{}", err);
+    }
+}
@@ -0,0 +1,222 @@
+// Copyright 2024 Peter Lyons Kehl
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! (De)serializes an [crate::Amount] as a map with a single entry, keyed by [MapKey::KEY] of its
+//! unit (like `{"bytes":1024}`), rather than as a bare `Repr` (which is what the default
+//! `Serialize` and `Deserialize` implementations do).
+//!
+//! That's needed for `#[serde(flatten)]`, which only works for fields that (de)serialize as maps.
+//! The entry then merges into the enclosing struct. When deserializing, entries with other keys
+//! are ignored (they belong to the enclosing struct's other fields).
+//!
+//! Opt in per field:
+//!
+//! ```
+//! #![cfg_attr(
+//!     feature = "unstable_generic_const_own_type",
+//!     feature(generic_const_exprs)
+//! )]
+//!
+//! #[cfg(feature = "serde")] {
+//! use phantom_newtype::serde_map::MapKey;
+//! use phantom_newtype::Amount;
+//! use serde::{Deserialize, Serialize};
+//!
+//! enum Bytes {}
+//! impl MapKey for Bytes {
+//!     const KEY: &'static str = "bytes";
+//! }
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Upload {
+//!     name: String,
+//!     #[serde(flatten, with = "phantom_newtype::serde_map")]
+//!     size: Amount<Bytes, u64>,
+//! }
+//!
+//! let upload = Upload { name: "a.txt".to_owned(), size: Amount::from(1024) };
+//! let json = serde_json::to_string(&upload).unwrap();
+//! assert_eq!(json, r#"{"name":"a.txt","bytes":1024}"#);
+//! assert_eq!(serde_json::from_str::<Upload>(&json).unwrap(), upload);
+//! }
+//! ```
+use crate::amount::Amount;
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+use crate::trait_flag::TraitFlags;
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, Visitor};
+use serde::ser::{SerializeMap, Serializer};
+use serde::{Deserialize, Serialize};
+
+/// The map key of a unit (marker) type. It's explicit (rather than derived from the type name),
+/// so that renaming the type doesn't change the serialized format.
+pub trait MapKey {
+    const KEY: &'static str;
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+pub fn serialize<S, const TF: TraitFlags, Unit, Repr>(
+    amount: &Amount<TF, Unit, Repr>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    Unit: MapKey,
+    Repr: Serialize,
+{
+    let mut map = serializer.serialize_map(Some(1))?;
+    map.serialize_entry(Unit::KEY, amount.get_ref())?;
+    map.end()
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+pub fn deserialize<'de, D, const TF: TraitFlags, Unit, Repr>(
+    deserializer: D,
+) -> Result<Amount<TF, Unit, Repr>, D::Error>
+where
+    D: Deserializer<'de>,
+    Unit: MapKey,
+    Repr: Deserialize<'de>,
+{
+    deserializer.deserialize_map(MapVisitor::<TF, Unit, Repr>(PhantomData))
+}
+
+/// [MapKey::KEY] of the unit.
+struct UnitKey(&'static str);
+
+// Deserializes a key to whether it's the unit key.
+impl<'de> DeserializeSeed<'de> for UnitKey {
+    type Value = bool;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<bool, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for UnitKey {
+    type Value = bool;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a map key")
+    }
+
+    fn visit_str<E: de::Error>(self, key: &str) -> Result<bool, E> {
+        Ok(key == self.0)
+    }
+
+    fn visit_bytes<E: de::Error>(self, key: &[u8]) -> Result<bool, E> {
+        Ok(key == self.0.as_bytes())
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+struct MapVisitor<const TF: TraitFlags, Unit, Repr>(PhantomData<fn() -> (Unit, Repr)>);
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<'de, const TF: TraitFlags, Unit, Repr> Visitor<'de> for MapVisitor<TF, Unit, Repr>
+where
+    Unit: MapKey,
+    Repr: Deserialize<'de>,
+{
+    type Value = Amount<TF, Unit, Repr>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a map with key `{}`", Unit::KEY)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut repr = None;
+        while let Some(is_unit_key) = map.next_key_seed(UnitKey(Unit::KEY))? {
+            if !is_unit_key {
+                map.next_value::<IgnoredAny>()?;
+            } else if repr.is_some() {
+                return Err(de::Error::custom(format_args!(
+                    "duplicate field `{}`",
+                    Unit::KEY
+                )));
+            } else {
+                repr = Some(map.next_value()?);
+            }
+        }
+        repr.map(Amount::new)
+            .ok_or_else(|| de::Error::custom(format_args!("missing field `{}`", Unit::KEY)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::MapKey;
+    use crate::Amount;
+    use serde::{Deserialize, Serialize};
+    use std::string::{String, ToString};
+
+    enum Bytes {}
+    impl MapKey for Bytes {
+        const KEY: &'static str = "bytes";
+    }
+    enum RowIndex {}
+    impl MapKey for RowIndex {
+        const KEY: &'static str = "row_index";
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Upload {
+        name: String,
+        #[serde(flatten, with = "crate::serde_map")]
+        size: Amount<Bytes, u64>,
+        #[serde(flatten, with = "crate::serde_map")]
+        row: Amount<RowIndex, u32>,
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Wrapped(#[serde(with = "crate::serde_map")] Amount<Bytes, u64>);
+
+    #[test]
+    fn test_flatten() {
+        let upload = Upload {
+            name: "a.txt".into(),
+            size: Amount::from(1024),
+            row: Amount::from(7),
+        };
+        let json = serde_json::to_string(&upload).unwrap();
+        assert_eq!(json, r#"{"name":"a.txt","bytes":1024,"row_index":7}"#);
+        assert_eq!(serde_json::from_str::<Upload>(&json).unwrap(), upload);
+
+        let reordered = r#"{"row_index":7,"bytes":1024,"name":"a.txt"}"#;
+        assert_eq!(serde_json::from_str::<Upload>(reordered).unwrap(), upload);
+
+        let missing = serde_json::from_str::<Upload>(r#"{"name":"a.txt","row_index":7}"#);
+        assert!(missing
+            .unwrap_err()
+            .to_string()
+            .contains("missing field `bytes`"));
+    }
+
+    #[test]
+    fn test_not_flattened() {
+        let wrapped = Wrapped(Amount::from(5));
+        let json = serde_json::to_string(&wrapped).unwrap();
+        assert_eq!(json, r#"{"bytes":5}"#);
+        assert_eq!(serde_json::from_str::<Wrapped>(&json).unwrap(), wrapped);
+
+        let duplicate = serde_json::from_str::<Wrapped>(r#"{"bytes":5,"bytes":6}"#);
+        assert!(duplicate
+            .unwrap_err()
+            .to_string()
+            .contains("duplicate field `bytes`"));
+    }
+}
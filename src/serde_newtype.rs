@@ -0,0 +1,148 @@
+// Copyright 2024 Peter Lyons Kehl
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! (De)serializes an [crate::Amount] as a newtype struct named after its unit, rather than as a
+//! bare `Repr` (which is what the default `Serialize` and `Deserialize` implementations do).
+//!
+//! Self-describing formats like JSON encode newtype structs just like the wrapped value. However,
+//! formats and tools that care about names (e.g. schema generators) see the unit name.
+//!
+//! Opt in per field:
+//!
+//! ```
+//! #![cfg_attr(
+//!     feature = "unstable_generic_const_own_type",
+//!     feature(generic_const_exprs)
+//! )]
+//!
+//! #[cfg(feature = "serde")] {
+//! use phantom_newtype::Amount;
+//! use serde::{Deserialize, Serialize};
+//!
+//! enum Meters {}
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Route {
+//!     #[serde(with = "phantom_newtype::serde_newtype")]
+//!     length: Amount<Meters, u64>,
+//! }
+//!
+//! let route = Route { length: Amount::from(42) };
+//! let json = serde_json::to_string(&route).unwrap();
+//! assert_eq!(json, r#"{"length":42}"#);
+//! assert_eq!(serde_json::from_str::<Route>(&json).unwrap(), route);
+//! }
+//! ```
+use crate::amount::Amount;
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+use crate::trait_flag::TraitFlags;
+use crate::type_name::short_type_name;
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::{Serialize, Serializer};
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+pub fn serialize<S, const TF: TraitFlags, Unit, Repr>(
+    amount: &Amount<TF, Unit, Repr>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    Repr: Serialize,
+{
+    serializer.serialize_newtype_struct(short_type_name::<Unit>(), amount.get_ref())
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+pub fn deserialize<'de, D, const TF: TraitFlags, Unit, Repr>(
+    deserializer: D,
+) -> Result<Amount<TF, Unit, Repr>, D::Error>
+where
+    D: Deserializer<'de>,
+    Repr: Deserialize<'de>,
+{
+    deserializer.deserialize_newtype_struct(
+        short_type_name::<Unit>(),
+        NewtypeVisitor::<TF, Unit, Repr>(PhantomData),
+    )
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+struct NewtypeVisitor<const TF: TraitFlags, Unit, Repr>(PhantomData<fn() -> (Unit, Repr)>);
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<'de, const TF: TraitFlags, Unit, Repr> Visitor<'de> for NewtypeVisitor<TF, Unit, Repr>
+where
+    Repr: Deserialize<'de>,
+{
+    type Value = Amount<TF, Unit, Repr>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "newtype struct {}", short_type_name::<Unit>())
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        Repr::deserialize(deserializer).map(Amount::new)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        match seq.next_element()? {
+            Some(repr) => Ok(Amount::new(repr)),
+            None => Err(de::Error::invalid_length(0, &self)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Amount;
+    use serde::{Deserialize, Serialize};
+    use serde_test::{assert_tokens, Token};
+
+    enum Meters {}
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Route {
+        #[serde(with = "crate::serde_newtype")]
+        newtype: Amount<Meters, u64>,
+        transparent: Amount<Meters, u64>,
+    }
+
+    #[test]
+    fn test_newtype_vs_transparent() {
+        let route = Route {
+            newtype: Amount::from(5),
+            transparent: Amount::from(7),
+        };
+        assert_tokens(
+            &route,
+            &[
+                Token::Struct {
+                    name: "Route",
+                    len: 2,
+                },
+                Token::Str("newtype"),
+                Token::NewtypeStruct { name: "Meters" },
+                Token::U64(5),
+                Token::Str("transparent"),
+                Token::U64(7),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
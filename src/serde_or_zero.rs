@@ -0,0 +1,141 @@
+// Copyright 2024 Peter Lyons Kehl
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! (De)serializes an `Option` of an integer [crate::Amount], with [None] as zero (like protobuf
+//! does for scalars): `None` serializes as `0`, and `0` deserializes as `None`. Hence `Some(0)`
+//! doesn't round-trip: It comes back as `None`.
+//!
+//! Opt in per field. Add `#[serde(default)]`, so that an absent field deserializes as `None`,
+//! too:
+//!
+//! ```
+//! #![cfg_attr(
+//!     feature = "unstable_generic_const_own_type",
+//!     feature(generic_const_exprs)
+//! )]
+//!
+//! #[cfg(feature = "serde")] {
+//! use phantom_newtype::Amount;
+//! use serde::{Deserialize, Serialize};
+//!
+//! enum Bytes {}
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Quota {
+//!     #[serde(default, with = "phantom_newtype::serde_or_zero")]
+//!     limit: Option<Amount<Bytes, u64>>,
+//! }
+//!
+//! let unlimited = Quota { limit: None };
+//! assert_eq!(serde_json::to_string(&unlimited).unwrap(), r#"{"limit":0}"#);
+//! assert_eq!(serde_json::from_str::<Quota>(r#"{"limit":0}"#).unwrap(), unlimited);
+//! assert_eq!(serde_json::from_str::<Quota>("{}").unwrap(), unlimited);
+//! }
+//! ```
+use crate::amount::Amount;
+use crate::ops::PrimitiveInteger;
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+use crate::trait_flag::TraitFlags;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+pub fn serialize<S, const TF: TraitFlags, Unit, Repr>(
+    amount: &Option<Amount<TF, Unit, Repr>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    Repr: PrimitiveInteger + Serialize,
+{
+    match amount {
+        Some(amount) => amount.get_ref().serialize(serializer),
+        None => Repr::ZERO.serialize(serializer),
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+pub fn deserialize<'de, D, const TF: TraitFlags, Unit, Repr>(
+    deserializer: D,
+) -> Result<Option<Amount<TF, Unit, Repr>>, D::Error>
+where
+    D: Deserializer<'de>,
+    Repr: PrimitiveInteger + Deserialize<'de> + PartialEq,
+{
+    let repr = Repr::deserialize(deserializer)?;
+    Ok(if repr == Repr::ZERO {
+        None
+    } else {
+        Some(Amount::new(repr))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Amount;
+    use serde::{Deserialize, Serialize};
+    use serde_test::{assert_de_tokens, assert_ser_tokens, assert_tokens, Token};
+
+    enum Bytes {}
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Quota(#[serde(with = "crate::serde_or_zero")] Option<Amount<Bytes, u64>>);
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Limits {
+        #[serde(default, with = "crate::serde_or_zero")]
+        soft: Option<Amount<Bytes, i32>>,
+    }
+
+    fn tokens(value: u64) -> [Token; 2] {
+        [Token::NewtypeStruct { name: "Quota" }, Token::U64(value)]
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert_tokens(&Quota(Some(Amount::from(5))), &tokens(5));
+        assert_tokens(&Quota(None), &tokens(0));
+
+        // `Some(0)` serializes like `None`, so it comes back as `None`.
+        assert_ser_tokens(&Quota(Some(Amount::from(0))), &tokens(0));
+        assert_de_tokens(&Quota(None), &tokens(0));
+    }
+
+    #[test]
+    fn test_absent() {
+        assert_de_tokens(
+            &Limits { soft: None },
+            &[
+                Token::Struct {
+                    name: "Limits",
+                    len: 0,
+                },
+                Token::StructEnd,
+            ],
+        );
+        assert_tokens(
+            &Limits {
+                soft: Some(Amount::from(-3)),
+            },
+            &[
+                Token::Struct {
+                    name: "Limits",
+                    len: 1,
+                },
+                Token::Str("soft"),
+                Token::I32(-3),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright 2024 Peter Lyons Kehl
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! (De)serializes an [crate::Amount] as a string, through [fmt::Display] and [FromStr] of its
+//! `Repr`, rather than as a bare `Repr` (which is what the default `Serialize` and `Deserialize`
+//! implementations do).
+//!
+//! That keeps large integers intact for consumers that parse JSON numbers as 64-bit floats (like
+//! JavaScript, which is exact up to 2^53 only).
+//!
+//! Opt in per field:
+//!
+//! ```
+//! #![cfg_attr(
+//!     feature = "unstable_generic_const_own_type",
+//!     feature(generic_const_exprs)
+//! )]
+//!
+//! #[cfg(feature = "serde")] {
+//! use phantom_newtype::Amount;
+//! use serde::{Deserialize, Serialize};
+//!
+//! enum Cents {}
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Balance {
+//!     #[serde(with = "phantom_newtype::serde_str")]
+//!     total: Amount<Cents, u64>,
+//! }
+//!
+//! let balance = Balance { total: Amount::from(12_345) };
+//! let json = serde_json::to_string(&balance).unwrap();
+//! assert_eq!(json, r#"{"total":"12345"}"#);
+//! assert_eq!(serde_json::from_str::<Balance>(&json).unwrap(), balance);
+//! }
+//! ```
+use crate::amount::Amount;
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+use crate::trait_flag::TraitFlags;
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+use serde::de::{self, Deserializer, Visitor};
+use serde::Serializer;
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+pub fn serialize<S, const TF: TraitFlags, Unit, Repr>(
+    amount: &Amount<TF, Unit, Repr>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    Repr: fmt::Display,
+{
+    serializer.collect_str(amount.get_ref())
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+pub fn deserialize<'de, D, const TF: TraitFlags, Unit, Repr>(
+    deserializer: D,
+) -> Result<Amount<TF, Unit, Repr>, D::Error>
+where
+    D: Deserializer<'de>,
+    Repr: FromStr,
+    Repr::Err: fmt::Display,
+{
+    deserializer.deserialize_str(StrVisitor::<TF, Unit, Repr>(PhantomData))
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+struct StrVisitor<const TF: TraitFlags, Unit, Repr>(PhantomData<fn() -> (Unit, Repr)>);
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<'de, const TF: TraitFlags, Unit, Repr> Visitor<'de> for StrVisitor<TF, Unit, Repr>
+where
+    Repr: FromStr,
+    Repr::Err: fmt::Display,
+{
+    type Value = Amount<TF, Unit, Repr>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string holding a number")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        s.parse().map(Amount::new).map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Amount;
+    use serde::{Deserialize, Serialize};
+
+    enum Cents {}
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Ledger {
+        #[serde(with = "crate::serde_str")]
+        as_str: Amount<Cents, u64>,
+        transparent: Amount<Cents, u64>,
+    }
+
+    #[test]
+    fn test_round_trip_above_2_pow_53() {
+        let big = (1u64 << 53) + 1;
+        let ledger = Ledger {
+            as_str: Amount::from(big),
+            transparent: Amount::from(7),
+        };
+        let json = serde_json::to_string(&ledger).unwrap();
+        assert_eq!(json, r#"{"as_str":"9007199254740993","transparent":7}"#);
+        assert_eq!(serde_json::from_str::<Ledger>(&json).unwrap(), ledger);
+
+        let max = r#"{"as_str":"18446744073709551615","transparent":0}"#;
+        assert_eq!(
+            serde_json::from_str::<Ledger>(max).unwrap().as_str,
+            Amount::from(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_rejects_non_strings() {
+        assert!(serde_json::from_str::<Ledger>(r#"{"as_str":5,"transparent":0}"#).is_err());
+        assert!(serde_json::from_str::<Ledger>(r#"{"as_str":"5x","transparent":0}"#).is_err());
+    }
+}
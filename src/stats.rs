@@ -0,0 +1,152 @@
+// Copyright 2024 Peter Lyons Kehl
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ops::{PrimitiveInteger, UsizeOps};
+use crate::Amount;
+use core::marker::PhantomData;
+use core::ops::{AddAssign, Div};
+
+/// Running statistics of [Amount]s of an integer `Repr` (like for telemetry). It's streaming: It
+/// doesn't store the amounts, just the aggregates, which keep the unit.
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::{Amount, AmountStats};
+///
+/// enum Millis {}
+///
+/// let mut latency = AmountStats::<Millis, u32>::new();
+/// for &ms in &[12, 30, 9] {
+///     latency.push(Amount::from(ms));
+/// }
+/// assert_eq!(latency.count(), 3);
+/// assert_eq!(latency.sum(), Amount::from(51));
+/// assert_eq!(latency.min(), Some(Amount::from(9)));
+/// assert_eq!(latency.max(), Some(Amount::from(30)));
+/// assert_eq!(latency.mean(), Some(Amount::from(17)));
+/// ```
+pub struct AmountStats<Unit, Repr> {
+    count: usize,
+    sum: Repr,
+    min: Option<Repr>,
+    max: Option<Repr>,
+    unit: PhantomData<core::sync::atomic::AtomicPtr<Unit>>,
+}
+
+impl<Unit, Repr> AmountStats<Unit, Repr>
+where
+    Repr: PrimitiveInteger + Ord + AddAssign + Div<Output = Repr> + UsizeOps,
+{
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            sum: Repr::ZERO,
+            min: None,
+            max: None,
+            unit: PhantomData,
+        }
+    }
+
+    /// Adds an amount to the statistics. The sum overflows like `+` does.
+    pub fn push(&mut self, amount: Amount<Unit, Repr>) {
+        let repr = amount.get();
+        self.count += 1;
+        self.sum += repr;
+        self.min = Some(self.min.map_or(repr, |min| min.min(repr)));
+        self.max = Some(self.max.map_or(repr, |max| max.max(repr)));
+    }
+
+    /// How many amounts were pushed.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The sum of the amounts (zero if there are none).
+    pub fn sum(&self) -> Amount<Unit, Repr> {
+        Amount::new(self.sum)
+    }
+
+    /// The smallest amount, or [None] if there are none.
+    pub fn min(&self) -> Option<Amount<Unit, Repr>> {
+        self.min.map(Amount::new)
+    }
+
+    /// The greatest amount, or [None] if there are none.
+    pub fn max(&self) -> Option<Amount<Unit, Repr>> {
+        self.max.map(Amount::new)
+    }
+
+    /// The arithmetic mean, rounded towards zero (like `/`), or [None] if there are no amounts
+    /// (or if the count doesn't fit `Repr`).
+    pub fn mean(&self) -> Option<Amount<Unit, Repr>> {
+        if self.count == 0 {
+            return None;
+        }
+        let count = Repr::try_from_usize(self.count).ok()?;
+        Some(Amount::new(self.sum / count))
+    }
+}
+
+impl<Unit, Repr> Default for AmountStats<Unit, Repr>
+where
+    Repr: PrimitiveInteger + Ord + AddAssign + Div<Output = Repr> + UsizeOps,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    enum Millis {}
+
+    #[test]
+    fn test_stats() {
+        let mut stats = AmountStats::<Millis, i64>::default();
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.sum(), Amount::from(0));
+        assert_eq!(stats.min(), None);
+        assert_eq!(stats.max(), None);
+        assert_eq!(stats.mean(), None);
+
+        for &ms in &[5, -3, 10, 2] {
+            stats.push(Amount::from(ms));
+        }
+        assert_eq!(stats.count(), 4);
+        assert_eq!(stats.sum(), Amount::from(14));
+        assert_eq!(stats.min(), Some(Amount::from(-3)));
+        assert_eq!(stats.max(), Some(Amount::from(10)));
+        assert_eq!(stats.mean(), Some(Amount::from(3)));
+
+        stats.push(Amount::from(-40));
+        assert_eq!(stats.mean(), Some(Amount::from(-5)));
+        assert_eq!(stats.min(), Some(Amount::from(-40)));
+    }
+
+    #[test]
+    fn test_count_beyond_repr() {
+        let mut stats = AmountStats::<Millis, u8>::new();
+        for _ in 0..300 {
+            stats.push(Amount::from(0));
+        }
+        assert_eq!(stats.count(), 300);
+        assert_eq!(stats.mean(), None);
+    }
+}
@@ -0,0 +1,281 @@
+// Copyright 2024 Peter Lyons Kehl
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::instant::Instant;
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+use crate::trait_flag::TraitFlags;
+use chrono::{DateTime, Datelike, Timelike, Utc};
+use core::fmt;
+
+const NANOS_PER_SEC: i64 = 1_000_000_000;
+
+/// Implement for a unit (marker) type to convert between [chrono::DateTime] and instants of that
+/// unit (with `i64` as `Repr`), counted from the Unix epoch.
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use chrono::{DateTime, Utc};
+/// use phantom_newtype::{Instant, TimeScale};
+///
+/// enum UnixMillis {}
+/// impl TimeScale for UnixMillis {
+///     const UNITS_PER_SECOND: i64 = 1_000;
+/// }
+/// type Timestamp = Instant<UnixMillis, i64>;
+///
+/// let dt = DateTime::<Utc>::from_timestamp(1_700_000_000, 123_000_000).unwrap();
+/// let ts = Timestamp::from_datetime(dt);
+/// assert_eq!(ts, Timestamp::from(1_700_000_000_123));
+/// assert_eq!(ts.to_datetime(), Some(dt));
+/// ```
+pub trait TimeScale {
+    /// How many units make one second. It must be positive, and it must divide 1_000_000_000
+    /// (so that one unit is a whole number of nanoseconds).
+    const UNITS_PER_SECOND: i64;
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit: TimeScale> Instant<TF, Unit, i64> {
+    /// Converts a [DateTime] to a whole number of units since the Unix epoch, truncating (towards
+    /// the past) any part finer than one unit.
+    ///
+    /// # Panics
+    ///
+    /// If the result overflows `i64`.
+    pub fn from_datetime(datetime: DateTime<Utc>) -> Self {
+        let units = datetime
+            .timestamp()
+            .checked_mul(Unit::UNITS_PER_SECOND)
+            .and_then(|units| {
+                units.checked_add(
+                    i64::from(datetime.timestamp_subsec_nanos())
+                        / (NANOS_PER_SEC / Unit::UNITS_PER_SECOND),
+                )
+            })
+            .expect("overflow when converting DateTime to an instant");
+        Self::new(units)
+    }
+
+    /// Converts to a [DateTime], or returns [None] if out of the range supported by [DateTime].
+    pub fn to_datetime(&self) -> Option<DateTime<Utc>> {
+        let units = *self.get_ref();
+        let secs = units.div_euclid(Unit::UNITS_PER_SECOND);
+        let nanos =
+            units.rem_euclid(Unit::UNITS_PER_SECOND) * (NANOS_PER_SEC / Unit::UNITS_PER_SECOND);
+        DateTime::from_timestamp(secs, nanos as u32)
+    }
+
+    /// Displays as ISO 8601 (RFC 3339) in UTC, like `2024-01-01T00:00:00Z`, without allocating.
+    /// Fractional seconds are shown with as many digits as one unit needs (three for
+    /// milliseconds...), and omitted for whole seconds. Years before 0000 or after 9999 have an
+    /// explicit sign and at least four digits, like `-0001-01-01T00:00:00Z` or
+    /// `+10000-01-01T00:00:00Z` (that's the ISO 8601 expanded form, which RFC 3339 doesn't
+    /// allow). If out of the range supported by [DateTime], this displays the bare `Repr`
+    /// instead.
+    ///
+    /// ```
+    /// #![cfg_attr(
+    ///     feature = "unstable_generic_const_own_type",
+    ///     feature(generic_const_exprs)
+    /// )]
+    ///
+    /// use phantom_newtype::{Instant, TimeScale};
+    ///
+    /// enum UnixMillis {}
+    /// impl TimeScale for UnixMillis {
+    ///     const UNITS_PER_SECOND: i64 = 1_000;
+    /// }
+    ///
+    /// let ts = Instant::<UnixMillis, i64>::from(1_704_067_200_042);
+    /// assert_eq!(ts.format_iso8601().to_string(), "2024-01-01T00:00:00.042Z");
+    /// ```
+    pub fn format_iso8601(&self) -> impl fmt::Display {
+        debug_assert!(
+            Unit::UNITS_PER_SECOND > 0 && NANOS_PER_SEC % Unit::UNITS_PER_SECOND == 0,
+            "TimeScale::UNITS_PER_SECOND must be positive and divide 1_000_000_000"
+        );
+        Iso8601 {
+            datetime: self.to_datetime(),
+            units: *self.get_ref(),
+            units_per_second: Unit::UNITS_PER_SECOND,
+        }
+    }
+}
+
+struct Iso8601 {
+    datetime: Option<DateTime<Utc>>,
+    units: i64,
+    units_per_second: i64,
+}
+
+impl fmt::Display for Iso8601 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let datetime = match self.datetime {
+            Some(datetime) => datetime,
+            None => return write!(f, "{}", self.units),
+        };
+        let year = datetime.year();
+        if (0..=9999).contains(&year) {
+            write!(f, "{:04}", year)?;
+        } else {
+            // The width includes the sign.
+            write!(f, "{:+05}", year)?;
+        }
+        write!(
+            f,
+            "-{:02}-{:02}T{:02}:{:02}:{:02}",
+            datetime.month(),
+            datetime.day(),
+            datetime.hour(),
+            datetime.minute(),
+            datetime.second()
+        )?;
+        // The fewest decimal digits that represent any multiple of one unit exactly. It's at most
+        // 9 if `UNITS_PER_SECOND` divides 10^9, as `TimeScale` requires. The bound keeps a
+        // non-conforming `UNITS_PER_SECOND` from looping (and overflowing) forever.
+        let mut digits = 0;
+        let mut power_of_ten = 1;
+        while digits < 9 && power_of_ten % self.units_per_second != 0 {
+            digits += 1;
+            power_of_ten *= 10;
+        }
+        if digits > 0 {
+            let fraction = datetime.nanosecond() / 10_u32.pow(9 - digits);
+            write!(f, ".{:0width$}", fraction, width = digits as usize)?;
+        }
+        f.write_str("Z")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use chrono::{DateTime, NaiveDate, Utc};
+
+    enum UnixSeconds {}
+    impl TimeScale for UnixSeconds {
+        const UNITS_PER_SECOND: i64 = 1;
+    }
+
+    enum UnixMillis {}
+    impl TimeScale for UnixMillis {
+        const UNITS_PER_SECOND: i64 = 1_000;
+    }
+
+    #[test]
+    fn test_seconds() {
+        type Ts = Instant<UnixSeconds, i64>;
+
+        let dt = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        assert_eq!(Ts::from_datetime(dt), Ts::from(1_700_000_000));
+        assert_eq!(Ts::from(1_700_000_000).to_datetime(), Some(dt));
+
+        let before_epoch = DateTime::<Utc>::from_timestamp(-5, 0).unwrap();
+        assert_eq!(
+            Ts::from_datetime(before_epoch).to_datetime(),
+            Some(before_epoch)
+        );
+
+        let with_nanos = DateTime::<Utc>::from_timestamp(10, 999_999_999).unwrap();
+        assert_eq!(Ts::from_datetime(with_nanos), Ts::from(10));
+
+        assert_eq!(Ts::from(i64::MAX).to_datetime(), None);
+    }
+
+    #[test]
+    fn test_millis() {
+        type Ts = Instant<UnixMillis, i64>;
+
+        let dt = DateTime::<Utc>::from_timestamp(1_700_000_000, 456_000_000).unwrap();
+        assert_eq!(Ts::from_datetime(dt), Ts::from(1_700_000_000_456));
+        assert_eq!(Ts::from_datetime(dt).to_datetime(), Some(dt));
+
+        let before_epoch = DateTime::<Utc>::from_timestamp(-2, 750_000_000).unwrap();
+        assert_eq!(Ts::from_datetime(before_epoch), Ts::from(-1_250));
+        assert_eq!(Ts::from(-1_250).to_datetime(), Some(before_epoch));
+    }
+
+    #[test]
+    fn test_format_iso8601() {
+        extern crate std;
+        use std::string::ToString;
+
+        enum Quarters {}
+        impl TimeScale for Quarters {
+            const UNITS_PER_SECOND: i64 = 4;
+        }
+
+        let seconds = |s: i64| {
+            Instant::<UnixSeconds, i64>::from(s)
+                .format_iso8601()
+                .to_string()
+        };
+        assert_eq!(seconds(0), "1970-01-01T00:00:00Z");
+        assert_eq!(seconds(1_704_067_200), "2024-01-01T00:00:00Z");
+        assert_eq!(seconds(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(seconds(-1), "1969-12-31T23:59:59Z");
+        assert_eq!(seconds(i64::MAX), i64::MAX.to_string());
+
+        let new_year = |year: i32| {
+            NaiveDate::from_ymd_opt(year, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .and_utc()
+                .timestamp()
+        };
+        assert_eq!(seconds(new_year(0)), "0000-01-01T00:00:00Z");
+        assert_eq!(seconds(new_year(9999)), "9999-01-01T00:00:00Z");
+        assert_eq!(seconds(new_year(-1)), "-0001-01-01T00:00:00Z");
+        assert_eq!(seconds(new_year(-12345)), "-12345-01-01T00:00:00Z");
+        assert_eq!(seconds(new_year(10000)), "+10000-01-01T00:00:00Z");
+
+        let millis = |ms: i64| {
+            Instant::<UnixMillis, i64>::from(ms)
+                .format_iso8601()
+                .to_string()
+        };
+        assert_eq!(millis(1_700_000_000_456), "2023-11-14T22:13:20.456Z");
+        assert_eq!(millis(-1_250), "1969-12-31T23:59:58.750Z");
+        assert_eq!(millis(5), "1970-01-01T00:00:00.005Z");
+
+        let quarters = Instant::<Quarters, i64>::from(3)
+            .format_iso8601()
+            .to_string();
+        assert_eq!(quarters, "1970-01-01T00:00:00.75Z");
+    }
+
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "must be positive and divide")
+    )]
+    fn test_format_iso8601_non_conforming_scale() {
+        extern crate std;
+        use std::string::ToString;
+
+        // Doesn't divide 10^9. In release builds, it's shown with (at most) 9 digits.
+        enum Thirds {}
+        impl TimeScale for Thirds {
+            const UNITS_PER_SECOND: i64 = 3;
+        }
+
+        let thirds = Instant::<Thirds, i64>::from(1).format_iso8601().to_string();
+        assert_eq!(thirds, "1970-01-01T00:00:00.333333333Z");
+    }
+}
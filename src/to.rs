@@ -12,17 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::amount::Amount;
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+use crate::trait_flag::TraitFlags;
 use core::marker::PhantomData;
 
-/// @TODO
-/// - copied()
-/// - cloned() <-> Iterator::cloned(self) => then use: .ref().cloned()
-/// - into()
-/// - ref(), mut() - same as Deref/DerefMut, but shorter.
-///
-/// multiple From<xxx> are OK! Multiple Into<xxxx> are OK, too.
-///
-/// AsRef, Borrow, Deref, DerefMut
+// @TODO
+// - copied()
+// - cloned() <-> Iterator::cloned(self) => then use: .ref().cloned()
+// - into()
+// - ref(), mut() - same as Deref/DerefMut, but shorter.
+//
+// multiple From<xxx> are OK! Multiple Into<xxxx> are OK, too.
+//
+// AsRef, Borrow, Deref, DerefMut
 
 fn _caller() {
     // A caller creates a value as Out.
@@ -100,6 +103,118 @@ where
     }
 }
 
+/// Indicator trait that activates a blanket `impl` of [TryTo]: Like [As], but the conversion can
+/// fail, as decided by [TryAs::check].
+pub trait TryAs<O> {
+    type Error;
+    fn check(&self) -> Result<(), Self::Error>;
+}
+
+/// Fallible version of [To], for [Amount](crate::Amount)s. `Target` is the amount type converted
+/// to (with the same trait flags and `Repr`).
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::{Amount, TryAs, TryTo};
+///
+/// enum Raw {}
+/// enum Percent {}
+/// impl TryAs<Percent> for Amount<Raw, u32> {
+///     type Error = ();
+///     fn check(&self) -> Result<(), ()> {
+///         if self.get() <= 100 { Ok(()) } else { Err(()) }
+///     }
+/// }
+///
+/// let percent: Result<Amount<Percent, u32>, _> = Amount::<Raw, u32>::from(42).try_to();
+/// assert_eq!(percent, Ok(Amount::from(42)));
+/// ```
+pub trait TryTo<Target>: Sized {
+    type Error;
+    fn try_to(self) -> Result<Target, Self::Error>;
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, T, Repr, O> TryTo<Amount<TF, O, Repr>> for Amount<TF, T, Repr>
+where
+    Self: TryAs<O>,
+{
+    type Error = <Self as TryAs<O>>::Error;
+
+    fn try_to(self) -> Result<Amount<TF, O, Repr>, Self::Error> {
+        self.check()?;
+        Ok(Amount::new(self.into_inner()))
+    }
+}
+
+/// Lifts [As] over slices of [Amount](crate::Amount)s: Reinterprets a whole slice as amounts of
+/// another unit, without copying. `Elem` is the target amount type (with the same trait flags and
+/// `Repr`).
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::{Amount, As, ToSlice};
+///
+/// enum Raw {}
+/// enum Scaled {}
+/// impl As<Scaled> for Amount<Raw, u32> {}
+///
+/// let raws = [Amount::<Raw, u32>::from(1), Amount::from(2)];
+/// let scaled: &[Amount<Scaled, u32>] = raws.to_slice();
+/// assert_eq!(scaled[1], Amount::from(2));
+/// ```
+pub trait ToSlice<Elem> {
+    fn to_slice(&self) -> &[Elem];
+}
+/// Mutable version of [ToSlice], activated by [AsMut].
+pub trait ToSliceMut<Elem> {
+    fn to_slice_mut(&mut self) -> &mut [Elem];
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, T, Repr, O> ToSlice<Amount<TF, O, Repr>> for [Amount<TF, T, Repr>]
+where
+    Amount<TF, T, Repr>: As<O>,
+{
+    fn to_slice(&self) -> &[Amount<TF, O, Repr>] {
+        // SAFETY: `Amount` is `#[repr(transparent)]` over `Repr` (the other field is a zero-sized
+        // `PhantomData`), whatever its unit. So `Amount<TF, T, Repr>` and `Amount<TF, O, Repr>`
+        // have the same size, alignment and validity. The length and lifetime are preserved.
+        unsafe { core::slice::from_raw_parts(self.as_ptr().cast(), self.len()) }
+    }
+}
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, T, Repr, O> ToSliceMut<Amount<TF, O, Repr>> for [Amount<TF, T, Repr>]
+where
+    Amount<TF, T, Repr>: AsMut<O>,
+{
+    fn to_slice_mut(&mut self) -> &mut [Amount<TF, O, Repr>] {
+        // SAFETY: See `to_slice`. The exclusive borrow is preserved.
+        unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr().cast(), self.len()) }
+    }
+}
+
+/// Lifts [As] over iterators of [Amount](crate::Amount)s, converting each to unit `O`. Collect the
+/// result to get a `Vec`, for example.
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+pub fn map_to<O, const TF: TraitFlags, T, Repr>(
+    iter: impl IntoIterator<Item = Amount<TF, T, Repr>>,
+) -> impl Iterator<Item = Amount<TF, O, Repr>>
+where
+    Amount<TF, T, Repr>: As<O>,
+{
+    iter.into_iter()
+        .map(|amount| Amount::new(amount.into_inner()))
+}
+
 // ------
 /// This trait doesn't have a generic parameter indicating the type we're transforming from.
 /// However, it has "From" in its name, because it's related to [AsFrom].
@@ -131,6 +246,32 @@ where
     }
 }
 
+/// Like [AsFrom], but the conversion can fail, as decided by [TryAsFrom::check].
+pub trait TryAsFrom<T>: Sized {
+    type Error;
+    fn check(from: &T) -> Result<(), Self::Error>;
+}
+
+/// Fallible version of [ToFrom], for [Amount](crate::Amount)s. Like [TryTo], but activated by
+/// [TryAsFrom] on `Target`.
+pub trait TryToFrom<Target>: Sized {
+    type Error;
+    fn try_to(self) -> Result<Target, Self::Error>;
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, T, Repr, O> TryToFrom<Amount<TF, O, Repr>> for Amount<TF, T, Repr>
+where
+    Amount<TF, O, Repr>: TryAsFrom<Self>,
+{
+    type Error = <Amount<TF, O, Repr> as TryAsFrom<Self>>::Error;
+
+    fn try_to(self) -> Result<Amount<TF, O, Repr>, Self::Error> {
+        <Amount<TF, O, Repr>>::check(&self)?;
+        Ok(Amount::new(self.into_inner()))
+    }
+}
+
 //-------
 // USERLAND:
 #[derive(Clone, Copy)]
@@ -170,3 +311,118 @@ pub fn _in_to_out<PROPERTY, UNIT>(inp: Amm<(In, PROPERTY), UNIT>) -> Amm<(Out, P
     // the above `impl` automatically enables this:
     inp.to()
 }
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+    use std::vec::Vec;
+
+    use std::vec;
+
+    #[derive(Clone, Copy)]
+    struct Raw;
+    #[derive(Clone, Copy)]
+    struct Scaled;
+
+    impl As<Scaled> for crate::Amount<Raw, u32> {}
+    impl AsMut<Scaled> for crate::Amount<Raw, u32> {}
+
+    #[test]
+    fn test_to_slice() {
+        let mut raws: Vec<crate::Amount<Raw, u32>> = vec![1.into(), 2.into(), 3.into()];
+        let scaled: &[crate::Amount<Scaled, u32>] = raws.to_slice();
+        assert_eq!(
+            scaled.iter().map(|a| a.get()).collect::<Vec<_>>(),
+            [1, 2, 3]
+        );
+
+        let scaled: &mut [crate::Amount<Scaled, u32>] = raws.to_slice_mut();
+        *scaled[1].get_mut() = 20;
+        assert_eq!(raws[1].get(), 20);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct OverHundred(u32);
+
+    #[derive(Clone, Copy)]
+    struct Percent;
+    impl TryAs<Percent> for crate::Amount<Raw, u32> {
+        type Error = OverHundred;
+        fn check(&self) -> Result<(), OverHundred> {
+            if self.get() <= 100 {
+                Ok(())
+            } else {
+                Err(OverHundred(self.get()))
+            }
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    struct NonZero;
+    impl TryAsFrom<crate::Amount<Raw, u32>> for crate::Amount<NonZero, u32> {
+        type Error = ();
+        fn check(from: &crate::Amount<Raw, u32>) -> Result<(), ()> {
+            if from.get() != 0 {
+                Ok(())
+            } else {
+                Err(())
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_to() {
+        let percent: Result<crate::Amount<Percent, u32>, _> =
+            TryTo::try_to(crate::Amount::<Raw, u32>::from(42));
+        assert_eq!(percent.map(|p| p.get()), Ok(42));
+
+        let percent: Result<crate::Amount<Percent, u32>, _> =
+            TryTo::try_to(crate::Amount::<Raw, u32>::from(101));
+        assert_eq!(percent.err(), Some(OverHundred(101)));
+    }
+
+    #[test]
+    fn test_try_to_from() {
+        let non_zero: Result<crate::Amount<NonZero, u32>, _> =
+            TryToFrom::try_to(crate::Amount::<Raw, u32>::from(7));
+        assert_eq!(non_zero.map(|n| n.get()), Ok(7));
+
+        let non_zero: Result<crate::Amount<NonZero, u32>, _> =
+            TryToFrom::try_to(crate::Amount::<Raw, u32>::from(0));
+        assert!(non_zero.is_err());
+    }
+
+    #[test]
+    fn test_map_to() {
+        let raws: Vec<crate::Amount<Raw, u32>> = vec![4.into(), 5.into()];
+        let scaled: Vec<crate::Amount<Scaled, u32>> = map_to(raws).collect();
+        assert_eq!(scaled.iter().map(|a| a.get()).collect::<Vec<_>>(), [4, 5]);
+    }
+}
+
+#[cfg(test)]
+mod prelude_tests {
+    // Nothing but the prelude (and the marker trait and types under test), so that this fails to
+    // compile if the prelude stops bringing the conversion methods into scope.
+    use super::Amm;
+    use crate::prelude::*;
+    use crate::As;
+    use core::marker::PhantomData;
+
+    struct Km;
+    struct Mi;
+    impl As<Mi> for Amm<Km, u32> {}
+    impl As<Mi> for Amount<Km, u32> {}
+
+    #[test]
+    fn test_to_via_prelude() {
+        let km: Amm<Km, u32> = Amm(PhantomData, 5);
+        let mi: Amm<Mi, u32> = km.to();
+        assert_eq!(mi.1, 5);
+
+        let kms = [Amount::<Km, u32>::from(7)];
+        let mis: &[Amount<Mi, u32>] = kms.to_slice();
+        assert_eq!(mis[0].get(), 7);
+    }
+}
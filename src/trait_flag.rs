@@ -1,5 +1,9 @@
-//! Unstable.
+//! Unstable: The encoding of the flags, and the items of this module, may change in any release,
+//! even a patch one (they are not covered by semantic versioning). Without feature
+//! `unstable_generic_const_own_type`, this module is public only for macros and `const` code that
+//! opt in to that, and it's hidden from the documentation.
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Eq, PartialEq, PartialOrd, core::fmt::Debug)]
 #[cfg_attr(
     feature = "unstable_generic_const_own_type",
@@ -23,6 +27,12 @@ pub enum TraitFlagsValues {
 /// [rust-lang/rust/issues/95174](https://github.com/rust-lang/rust/issues/95174), we switch to
 /// using a proper struct here (which will derive [core::marker::ConstParamTy], derive/implement
 /// [PartialEq] and [Eq]).
+///
+/// [Amount]: crate::Amount
+/// [AmountNoCopy]: crate::AmountNoCopy
+/// [AmountNoCopyNoDefault]: crate::AmountNoCopyNoDefault
+/// [Id]: crate::Id
+/// [Instant]: crate::Instant
 #[cfg(not(feature = "unstable_generic_const_own_type"))]
 pub type TraitFlags = u8;
 #[cfg(feature = "unstable_generic_const_own_type")]
@@ -45,9 +55,7 @@ const fn trait_flags_new(tfv: TraitFlagsValues) -> TraitFlags {
     }
 }
 
-#[cfg(test)]
 const TRAIT_FLAG_BIT_COPY: u8 = 0b1;
-#[cfg(test)]
 const TRAIT_FLAG_BIT_DEFAULT: u8 = 0b10;
 
 #[cfg_attr(
@@ -79,7 +87,6 @@ pub const TRAIT_FLAGS_NO_COPY_IS_DEFAULT: TraitFlags =
 pub const TRAIT_FLAGS_NO_COPY_NO_DEFAULT: TraitFlags =
     trait_flags_new(TraitFlagsValues::TraitFlagsNoCopyNoDefault);
 
-#[cfg(test)]
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
 const fn trait_flags_bits(tf: TraitFlags) -> u8 {
     #[cfg(not(feature = "unstable_generic_const_own_type"))]
@@ -88,16 +95,38 @@ const fn trait_flags_bits(tf: TraitFlags) -> u8 {
     return tf as u8;
 }
 
-#[cfg(test)]
+/// Whether the flags make [Amount](crate::Amount), [Id](crate::Id) or [Instant](crate::Instant) implement [Copy] (if `Repr` does).
+#[cfg_attr(
+    feature = "unstable_generic_const_own_type",
+    deprecated(note = "`nightly` warning: Direct use is unstable!")
+)]
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-const fn is_copy(flags: TraitFlags) -> bool {
+pub const fn is_copy(flags: TraitFlags) -> bool {
     trait_flags_bits(flags) & TRAIT_FLAG_BIT_COPY != 0
 }
-#[cfg(test)]
+/// Whether the flags make [Amount](crate::Amount), [Id](crate::Id) or [Instant](crate::Instant) implement [Default] (if `Repr` does).
+#[cfg_attr(
+    feature = "unstable_generic_const_own_type",
+    deprecated(note = "`nightly` warning: Direct use is unstable!")
+)]
 #[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
-const fn is_default(flags: TraitFlags) -> bool {
+pub const fn is_default(flags: TraitFlags) -> bool {
     trait_flags_bits(flags) & TRAIT_FLAG_BIT_DEFAULT != 0
 }
+/// The flags with the given properties. The inverse of [is_copy] and [is_default].
+#[cfg_attr(
+    feature = "unstable_generic_const_own_type",
+    deprecated(note = "`nightly` warning: Direct use is unstable!")
+)]
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+pub const fn trait_flags(is_copy: bool, is_default: bool) -> TraitFlags {
+    trait_flags_new(match (is_copy, is_default) {
+        (false, false) => TraitFlagsValues::TraitFlagsNoCopyNoDefault,
+        (true, false) => TraitFlagsValues::TraitFlagsIsCopyNoDefault,
+        (false, true) => TraitFlagsValues::TraitFlagsNoCopyIsDefault,
+        (true, true) => TraitFlagsValues::TraitFlagsIsCopyIsDefault,
+    })
+}
 #[cfg(test)]
 mod test_flags {
     extern crate std;
@@ -106,15 +135,43 @@ mod test_flags {
     #[test]
     #[allow(deprecated)]
     fn all() {
-        assert_eq!(is_copy(TRAIT_FLAGS_IS_COPY_IS_DEFAULT), true);
-        assert_eq!(is_copy(TRAIT_FLAGS_IS_COPY_NO_DEFAULT), true);
-        assert_eq!(is_copy(TRAIT_FLAGS_NO_COPY_IS_DEFAULT), false);
-        assert_eq!(is_copy(TRAIT_FLAGS_NO_COPY_NO_DEFAULT), false);
+        assert!(is_copy(TRAIT_FLAGS_IS_COPY_IS_DEFAULT));
+        assert!(is_copy(TRAIT_FLAGS_IS_COPY_NO_DEFAULT));
+        assert!(!is_copy(TRAIT_FLAGS_NO_COPY_IS_DEFAULT));
+        assert!(!is_copy(TRAIT_FLAGS_NO_COPY_NO_DEFAULT));
+
+        assert!(is_default(TRAIT_FLAGS_IS_COPY_IS_DEFAULT));
+        assert!(is_default(TRAIT_FLAGS_NO_COPY_IS_DEFAULT));
+        assert!(!is_default(TRAIT_FLAGS_IS_COPY_NO_DEFAULT));
+        assert!(!is_default(TRAIT_FLAGS_NO_COPY_NO_DEFAULT));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn const_context() {
+        const IS_COPY_NO_DEFAULT: TraitFlags = trait_flags(true, false);
+        // `assert!` in a `const` needs Rust 1.57. This fails to compile (mismatched array length)
+        // unless the condition holds.
+        const _: [(); 1] =
+            [(); (is_copy(IS_COPY_NO_DEFAULT) && !is_default(IS_COPY_NO_DEFAULT)) as usize];
+
+        const _: [(); 1] = [(); {
+            let mut all = true;
+            let mut bits = 0;
+            while bits < 4 {
+                let (copy, default) = (bits & 1 != 0, bits & 2 != 0);
+                all = all
+                    && is_copy(trait_flags(copy, default)) == copy
+                    && is_default(trait_flags(copy, default)) == default;
+                bits += 1;
+            }
+            all
+        } as usize];
 
-        assert_eq!(is_default(TRAIT_FLAGS_IS_COPY_IS_DEFAULT), true);
-        assert_eq!(is_default(TRAIT_FLAGS_NO_COPY_IS_DEFAULT), true);
-        assert_eq!(is_default(TRAIT_FLAGS_IS_COPY_NO_DEFAULT), false);
-        assert_eq!(is_default(TRAIT_FLAGS_NO_COPY_NO_DEFAULT), false);
+        assert_eq!(trait_flags(true, true), TRAIT_FLAGS_IS_COPY_IS_DEFAULT);
+        assert_eq!(trait_flags(true, false), TRAIT_FLAGS_IS_COPY_NO_DEFAULT);
+        assert_eq!(trait_flags(false, true), TRAIT_FLAGS_NO_COPY_IS_DEFAULT);
+        assert_eq!(trait_flags(false, false), TRAIT_FLAGS_NO_COPY_NO_DEFAULT);
     }
 }
 
@@ -0,0 +1,47 @@
+// Copyright 2024 Peter Lyons Kehl
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Like [core::any::type_name], but without the module path of the (outer) type. E.g.
+/// `Cents` rather than `my_crate::money::Cents`, or `Vec<alloc::string::String>` rather than
+/// `alloc::vec::Vec<alloc::string::String>`.
+///
+/// Just like [core::any::type_name], this is for diagnostics only: the exact output is not
+/// guaranteed.
+pub(crate) fn short_type_name<T: ?Sized>() -> &'static str {
+    let name = core::any::type_name::<T>();
+    let outer = match name.find('<') {
+        Some(generics) => &name[..generics],
+        None => name,
+    };
+    match outer.rfind("::") {
+        Some(path) => &name[path + 2..],
+        None => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::short_type_name;
+
+    #[test]
+    fn test_short_type_name() {
+        struct Cents;
+        assert_eq!(short_type_name::<Cents>(), "Cents");
+        assert_eq!(short_type_name::<u64>(), "u64");
+        assert_eq!(
+            short_type_name::<core::marker::PhantomData<Cents>>(),
+            &core::any::type_name::<core::marker::PhantomData<Cents>>()["core::marker::".len()..]
+        );
+    }
+}
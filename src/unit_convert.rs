@@ -0,0 +1,122 @@
+// Copyright 2024 Peter Lyons Kehl
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::amount::Amount;
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+use crate::trait_flag::TraitFlags;
+use core::convert::TryFrom;
+use core::fmt::Debug;
+use core::ops::{Div, Mul};
+
+/// Implement for a unit (marker) type to enable `Amount::convert` to unit `To`. One unit of
+/// `Self` equals `NUM / DEN` units of `To`.
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::{Amount, UnitConvert};
+///
+/// enum Kilometers {}
+/// enum Meters {}
+/// impl UnitConvert<Meters> for Kilometers {
+///     const NUM: u64 = 1_000;
+///     const DEN: u64 = 1;
+/// }
+///
+/// let distance = Amount::<Kilometers, u64>::from(3);
+/// assert_eq!(distance.convert::<Meters>(), Amount::<Meters, u64>::from(3_000));
+/// ```
+pub trait UnitConvert<To> {
+    /// Numerator of the ratio.
+    const NUM: u64;
+    /// Denominator of the ratio. It must not be zero.
+    const DEN: u64;
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit, Repr> Amount<TF, Unit, Repr>
+where
+    Repr: Mul<Output = Repr> + Div<Output = Repr> + TryFrom<u64>,
+    <Repr as TryFrom<u64>>::Error: Debug,
+{
+    /// Converts to unit `To`, multiplying by [UnitConvert::NUM] and then dividing by
+    /// [UnitConvert::DEN]. Like the division of `Repr`, it truncates (for integers that is toward
+    /// zero).
+    ///
+    /// # Panics
+    ///
+    /// If `NUM` or `DEN` doesn't fit into `Repr`, or if the multiplication overflows (in debug
+    /// builds, as any arithmetics of `Repr` does).
+    #[must_use]
+    pub fn convert<To>(self) -> Amount<TF, To, Repr>
+    where
+        Unit: UnitConvert<To>,
+    {
+        let num = Repr::try_from(<Unit as UnitConvert<To>>::NUM)
+            .expect("UnitConvert::NUM doesn't fit into Repr");
+        let den = Repr::try_from(<Unit as UnitConvert<To>>::DEN)
+            .expect("UnitConvert::DEN doesn't fit into Repr");
+        Amount::new(self.into_inner() * num / den)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    enum Kilometers {}
+    enum Meters {}
+
+    impl UnitConvert<Meters> for Kilometers {
+        const NUM: u64 = 1_000;
+        const DEN: u64 = 1;
+    }
+    impl UnitConvert<Kilometers> for Meters {
+        const NUM: u64 = 1;
+        const DEN: u64 = 1_000;
+    }
+
+    #[test]
+    fn test_km_to_m() {
+        assert_eq!(
+            Amount::<Kilometers, u64>::from(42).convert::<Meters>(),
+            Amount::<Meters, u64>::from(42_000)
+        );
+        assert_eq!(
+            AmountNoCopy::<Kilometers, i32>::from(-2).convert::<Meters>(),
+            AmountNoCopy::<Meters, i32>::from(-2_000)
+        );
+    }
+
+    #[test]
+    fn test_m_to_km_truncates() {
+        assert_eq!(
+            Amount::<Meters, u64>::from(2_999).convert::<Kilometers>(),
+            Amount::<Kilometers, u64>::from(2)
+        );
+        assert_eq!(
+            Amount::<Meters, i64>::from(-1_500).convert::<Kilometers>(),
+            Amount::<Kilometers, i64>::from(-1)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "UnitConvert::DEN doesn't fit into Repr")]
+    fn test_ratio_doesnt_fit() {
+        let _ = Amount::<Meters, u8>::from(200).convert::<Kilometers>();
+    }
+}
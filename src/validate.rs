@@ -0,0 +1,103 @@
+// Copyright 2024 Peter Lyons Kehl
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::amount::Amount;
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+use crate::trait_flag::TraitFlags;
+use core::fmt;
+
+/// Implement for a unit (marker) type to restrict which `Repr` values `Amount::try_new` accepts.
+///
+/// Only `try_new` checks it: `new` and `from` stay unchecked (and so does arithmetic).
+///
+/// ```
+/// #![cfg_attr(
+///     feature = "unstable_generic_const_own_type",
+///     feature(generic_const_exprs)
+/// )]
+///
+/// use phantom_newtype::{Amount, OutOfRange, Validate};
+///
+/// enum Percent {}
+/// impl Validate<u8> for Percent {
+///     fn validate(repr: &u8) -> bool {
+///         *repr <= 100
+///     }
+/// }
+///
+/// assert_eq!(Amount::<Percent, u8>::try_new(42), Ok(Amount::from(42)));
+/// assert_eq!(Amount::<Percent, u8>::try_new(101), Err(OutOfRange));
+/// ```
+pub trait Validate<Repr> {
+    /// Whether `repr` is a valid value for amounts of this unit.
+    fn validate(repr: &Repr) -> bool;
+}
+
+/// Error of `Amount::try_new`, when [Validate::validate] rejects the value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutOfRange;
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("value out of range")
+    }
+}
+
+#[cfg_attr(feature = "unstable_generic_const_own_type", allow(deprecated))]
+impl<const TF: TraitFlags, Unit: Validate<Repr>, Repr> Amount<TF, Unit, Repr> {
+    /// Creates an amount, if `Unit` accepts `repr` (see [Validate]).
+    pub fn try_new(repr: Repr) -> Result<Self, OutOfRange> {
+        if Unit::validate(&repr) {
+            Ok(Self::new(repr))
+        } else {
+            Err(OutOfRange)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    enum Percent {}
+    impl Validate<u8> for Percent {
+        fn validate(repr: &u8) -> bool {
+            *repr <= 100
+        }
+    }
+    type Percentage = Amount<Percent, u8>;
+
+    #[test]
+    fn test_valid() {
+        assert_eq!(Percentage::try_new(0), Ok(Percentage::from(0)));
+        assert_eq!(Percentage::try_new(55), Ok(Percentage::from(55)));
+        assert_eq!(Percentage::try_new(100), Ok(Percentage::from(100)));
+        assert_eq!(
+            AmountNoCopy::<Percent, u8>::try_new(100),
+            Ok(AmountNoCopy::new(100))
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        extern crate std;
+        use std::string::ToString;
+
+        assert_eq!(Percentage::try_new(101), Err(OutOfRange));
+        assert_eq!(Percentage::try_new(u8::MAX), Err(OutOfRange));
+        assert_eq!(OutOfRange.to_string(), "value out of range");
+        // `new` and `from` stay unchecked.
+        assert_eq!(Percentage::new(200).get(), 200);
+    }
+}